@@ -0,0 +1,137 @@
+use std::fmt;
+
+/// Creates an informational notice which is shown to the user without being an error.
+///
+/// Sometimes you need to give the user advice proactively, such as warning them
+/// that a flag is deprecated or that an operation may be slow, without anything
+/// having actually failed. An [Advisory] carries a description and advice just like
+/// an [crate::Error], but it is rendered as a note rather than a failure.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let note = human_errors::advisory(
+///   "The --config-dir flag has been deprecated.",
+///   "Use the --config flag to provide the path to your config file instead.",
+/// );
+///
+/// assert_eq!(
+///   note.message(),
+///   "Note: The --config-dir flag has been deprecated.\n\nYou may want to:\n - Use the --config flag to provide the path to your config file instead."
+/// );
+/// ```
+pub fn advisory(description: &str, advice: &str) -> Advisory {
+    Advisory {
+        description: description.to_string(),
+        advice: advice.to_string(),
+    }
+}
+
+/// Creates a notice that something has been deprecated.
+///
+/// Produces an [Advisory] with consistent wording for deprecations, optionally
+/// mentioning the version in which the deprecated `item` will be removed and
+/// what should be used instead.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let note = human_errors::deprecation("The --old flag", Some("the --new flag"), Some("2.0"));
+///
+/// assert_eq!(
+///   note.message(),
+///   "Note: The --old flag is deprecated and will be removed in 2.0.\n\nYou may want to:\n - Use the --new flag instead."
+/// );
+/// ```
+pub fn deprecation(item: &str, replacement: Option<&str>, removal: Option<&str>) -> Advisory {
+    let description = match removal {
+        Some(removal) => format!("{} is deprecated and will be removed in {}.", item, removal),
+        None => format!(
+            "{} is deprecated and will be removed in a future release.",
+            item
+        ),
+    };
+
+    let advice = match replacement {
+        Some(replacement) => format!("Use {} instead.", replacement),
+        None => String::new(),
+    };
+
+    advisory(&description, &advice)
+}
+
+/// An informational notice for the user, created by [advisory].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Advisory {
+    description: String,
+    advice: String,
+}
+
+impl Advisory {
+    /// Gets the description of this notice.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Gets the advice associated with this notice, which may be empty.
+    pub fn advice(&self) -> &str {
+        &self.advice
+    }
+
+    /// Gets the formatted notice and its advice.
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Advisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "[note] {}", self.description);
+        }
+
+        write!(f, "Note: {}", self.description)?;
+
+        if !self.advice.is_empty() {
+            write!(f, "\n\nYou may want to:\n - {}", self.advice)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advisory() {
+        let note = advisory("This may take a few minutes.", "");
+        assert_eq!(note.message(), "Note: This may take a few minutes.");
+        assert_eq!(format!("{:#}", note), "[note] This may take a few minutes.");
+
+        let note = advisory(
+            "The --config-dir flag has been deprecated.",
+            "Use the --config flag instead.",
+        );
+        assert_eq!(
+            note.description(),
+            "The --config-dir flag has been deprecated."
+        );
+        assert_eq!(note.advice(), "Use the --config flag instead.");
+        assert_eq!(
+            note.message(),
+            "Note: The --config-dir flag has been deprecated.\n\nYou may want to:\n - Use the --config flag instead."
+        );
+    }
+
+    #[test]
+    fn test_deprecation() {
+        assert_eq!(
+            deprecation("The --old flag", None, None).message(),
+            "Note: The --old flag is deprecated and will be removed in a future release."
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::RwLock;
+
+static CATALOG: RwLock<Option<Box<dyn AdviceCatalog>>> = RwLock::new(None);
+
+/// A source of translations for the messages used by built-in conversions.
+///
+/// The `From` conversions provided by this crate (for example, from [std::io::Error])
+/// include English descriptions and advice. Each of these messages is identified by
+/// a key, allowing you to provide translations for them by registering an
+/// [AdviceCatalog] with [set_advice_catalog]. Any key which your catalog does not
+/// translate will fall back to the default English text.
+///
+/// This trait is implemented for any `Fn(&str) -> Option<String>`, so you can use a
+/// closure if you don't need a dedicated type.
+pub trait AdviceCatalog: Send + Sync {
+    /// Gets the translation for the message identified by `key`, if there is one.
+    fn translate(&self, key: &str) -> Option<String>;
+}
+
+impl<F> AdviceCatalog for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    fn translate(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
+
+/// Registers the catalog used to translate the messages of built-in conversions.
+///
+/// Replaces any previously registered catalog, which you can remove again with
+/// [clear_advice_catalog]. The keys used by the built-in conversions are:
+///
+/// | Key                    | Used for                                               |
+/// |------------------------|--------------------------------------------------------|
+/// | `io.not_found.*`       | [std::io::ErrorKind::NotFound]                         |
+/// | `io.connection.*`      | `TimedOut`, `ConnectionRefused` and `ConnectionReset`  |
+/// | `io.broken_pipe.*`     | [std::io::ErrorKind::BrokenPipe]                       |
+/// | `io.other.*`           | Any other [std::io::ErrorKind]                         |
+/// | `convert.slice.*`      | [std::array::TryFromSliceError]                        |
+/// | `convert.int.*`        | [std::num::TryFromIntError]                            |
+/// | `alloc.try_reserve.*`  | [std::collections::TryReserveError]                    |
+/// | `ffi.nul.*`            | [std::ffi::NulError]                                   |
+/// | `ffi.vec_with_nul.*`   | [std::ffi::FromVecWithNulError]                        |
+/// | `net.addr_parse.*`     | [std::net::AddrParseError]                             |
+/// | `parse.int.*`          | [std::num::ParseIntError]                              |
+/// | `parse.float.*`        | [std::num::ParseFloatError]                            |
+/// | `parse.char.*`         | [std::char::ParseCharError]                            |
+/// | `parse.bool.*`         | [std::str::ParseBoolError]                             |
+/// | `sync.poison.*`        | [std::sync::PoisonError]                               |
+/// | `regex.syntax.*`       | `regex::Error::Syntax` (`regex` feature)               |
+/// | `regex.too_big.*`      | `regex::Error::CompiledTooBig` (`regex` feature)       |
+/// | `toml.*`               | `toml::de::Error` (`toml` feature)                     |
+/// | `yaml.*`               | `serde_norway::Error` (`yaml` feature)                 |
+/// | `panic.*`              | [crate::from_panic]                                    |
+/// | `validate.*`           | [crate::Validator::finish]                             |
+///
+/// Each key has a `.description` and `.advice` suffix, for example `io.not_found.advice`,
+/// except for `validate.*` which only has a `.description`.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::set_advice_catalog(|key: &str| match key {
+///   "io.not_found.description" => Some("Le fichier demandé est introuvable.".to_string()),
+///   _ => None,
+/// });
+/// ```
+pub fn set_advice_catalog<C>(catalog: C)
+where
+    C: AdviceCatalog + 'static,
+{
+    let mut current = CATALOG.write().unwrap_or_else(|err| err.into_inner());
+    *current = Some(Box::new(catalog));
+}
+
+/// Removes the registered catalog, so that built-in conversions use their default
+/// English messages once again.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::set_advice_catalog(|_key: &str| Some("Traduit".to_string()));
+/// human_errors::clear_advice_catalog();
+///
+/// let err: human_errors::Error = std::io::Error::from(std::io::ErrorKind::NotFound).into();
+/// assert_ne!(err.description(), "Traduit");
+/// ```
+pub fn clear_advice_catalog() {
+    let mut current = CATALOG.write().unwrap_or_else(|err| err.into_inner());
+    *current = None;
+}
+
+pub(crate) fn translate(key: &str, default: &str) -> String {
+    let catalog = CATALOG.read().unwrap_or_else(|err| err.into_inner());
+
+    catalog
+        .as_ref()
+        .and_then(|catalog| catalog.translate(key))
+        .unwrap_or_else(|| default.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate("test.untranslated", "Hello world"), "Hello world");
+
+        set_advice_catalog(|key: &str| match key {
+            "test.translated" => Some("Bonjour le monde".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(
+            translate("test.translated", "Hello world"),
+            "Bonjour le monde"
+        );
+        assert_eq!(translate("test.untranslated", "Hello world"), "Hello world");
+
+        // The catalog is global, so remove it again to avoid affecting other tests.
+        clear_advice_catalog();
+        assert_eq!(translate("test.translated", "Hello world"), "Hello world");
+    }
+}
@@ -0,0 +1,95 @@
+use super::{
+    system, system_with_cause, system_with_internal, user, user_with_cause, user_with_internal,
+    Error,
+};
+use std::error;
+
+/// Adds context to failures, in the style of `anyhow`'s `Context` trait.
+///
+/// Implemented for [Result]s whose error can be boxed as a [std::error::Error], and for
+/// [Option]s (where `None` is treated as the failure). Each call adds a new layer to
+/// the causal chain, so calls can be chained freely to build up a readable description
+/// of what went wrong. If the error is already an [Error], it is added as a cause so
+/// that its advice is preserved; otherwise it is added as an internal error.
+///
+/// # Examples
+/// ```
+/// use human_errors::Context;
+///
+/// let port: Result<u16, human_errors::Error> = "http"
+///   .parse::<u16>()
+///   .context("The port number was not valid.")
+///   .context("We could not load your server configuration.");
+///
+/// assert!(port.unwrap_err().is_system());
+/// ```
+pub trait Context<T> {
+    /// Wraps the failure in a system [Error] with the provided description and no advice.
+    fn context(self, description: &str) -> Result<T, Error>;
+
+    /// Wraps the failure in a user [Error] with the provided description and advice.
+    fn user_context(self, description: &str, advice: &str) -> Result<T, Error>;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+where
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+{
+    fn context(self, description: &str) -> Result<T, Error> {
+        self.map_err(|err| match err.into().downcast::<Error>() {
+            Ok(cause) => system_with_cause(description, "", *cause),
+            Err(internal) => system_with_internal(description, "", internal),
+        })
+    }
+
+    fn user_context(self, description: &str, advice: &str) -> Result<T, Error> {
+        self.map_err(|err| match err.into().downcast::<Error>() {
+            Ok(cause) => user_with_cause(description, advice, *cause),
+            Err(internal) => user_with_internal(description, advice, internal),
+        })
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context(self, description: &str) -> Result<T, Error> {
+        self.ok_or_else(|| system(description, ""))
+    }
+
+    fn user_context(self, description: &str, advice: &str) -> Result<T, Error> {
+        self.ok_or_else(|| user(description, advice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_context() {
+        let err = "http"
+            .parse::<u16>()
+            .context("The port number was not valid.")
+            .user_context(
+                "We could not load your server configuration.",
+                "Make sure that the port is a number between 0 and 65535.",
+            )
+            .unwrap_err();
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.message(),
+            "Oh no! We could not load your server configuration.\n\nThis was caused by:\n - The port number was not valid.\n - invalid digit found in string\n\nTo try and fix this, you can:\n - Make sure that the port is a number between 0 and 65535."
+        );
+    }
+
+    #[test]
+    fn test_option_context() {
+        let value: Option<u16> = None;
+        let err = value.context("No port was configured.").unwrap_err();
+
+        assert!(err.is_system());
+        assert_eq!(err.description(), "No port was configured.");
+
+        assert_eq!(Some(8080).context("No port was configured.").unwrap(), 8080);
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::RwLock;
+
+static DEFAULT_SYSTEM_ADVICE: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Sets advice which is given for every system error.
+///
+/// System errors are, by definition, not something the user can fix on their
+/// own, so they often end up with the same boilerplate advice (such as asking
+/// the user to report the problem). Registering that advice here saves you from
+/// repeating it at every call site.
+///
+/// Whenever an error's causal chain contains a system error, this advice is listed
+/// after all of the advice provided by the chain itself, in the order given here.
+/// Any default advice which is identical to advice already provided by the chain
+/// is omitted. By default, no advice is registered.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::set_default_system_advice(&["Report this problem at https://github.com/my/app/issues."]);
+///
+/// let err = human_errors::system("We could not load the config template.", "Reinstall the application.");
+/// assert!(err.message().ends_with(
+///   " - Reinstall the application.\n - Report this problem at https://github.com/my/app/issues."
+/// ));
+///
+/// let err = human_errors::user("The port you provided was invalid.", "Provide a port between 1 and 65535.");
+/// assert!(!err.message().contains("Report this problem"));
+///
+/// let err = human_errors::system("We could not load the config template.", "");
+/// assert!(!err.advice_is_empty());
+/// assert_eq!(err.advice_count(), 1);
+/// ```
+pub fn set_default_system_advice(advice: &[&str]) {
+    let mut current = DEFAULT_SYSTEM_ADVICE
+        .write()
+        .unwrap_or_else(|err| err.into_inner());
+    *current = advice.iter().map(|advice| advice.to_string()).collect();
+}
+
+pub(crate) fn default_system_advice() -> Vec<String> {
+    DEFAULT_SYSTEM_ADVICE
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+}
+
+pub(crate) fn with_default_system_advice<R>(f: impl FnOnce(&[String]) -> R) -> R {
+    f(&DEFAULT_SYSTEM_ADVICE
+        .read()
+        .unwrap_or_else(|err| err.into_inner()))
+}
@@ -4,6 +4,8 @@ use std::fmt;
 ///
 /// Generates a [std::error::Error] compatible error for the given
 /// message. Can be used as the internal error for an [crate::Error].
+/// The message may be any type which can be converted into a [String],
+/// including both `&str` and [String].
 ///
 /// # Examples
 /// ```
@@ -14,25 +16,67 @@ use std::fmt;
 ///   "Make sure that the file exists and is readable by the application.",
 ///   human_errors::detailed_message("ENOENT 2: No such file or directory")
 /// );
+///
+/// human_errors::user_with_internal(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+///   human_errors::detailed_message(format!("ENOENT {}: No such file or directory", 2))
+/// );
 /// ```
-pub fn detailed_message(message: &str) -> BasicInternalError {
-    message.into()
+pub fn detailed_message<S>(message: S) -> BasicInternalError
+where
+    S: Into<String>,
+{
+    BasicInternalError {
+        message: message.into(),
+        source: None,
+    }
 }
 
 #[derive(Debug)]
 pub struct BasicInternalError {
     message: String,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl BasicInternalError {
+    pub(crate) fn with_source(
+        message: &str,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    ) -> Self {
+        Self {
+            message: message.to_string(),
+            source: Some(source),
+        }
+    }
 }
 
 impl From<&str> for BasicInternalError {
     fn from(s: &str) -> Self {
         Self {
             message: s.to_string(),
+            source: None,
+        }
+    }
+}
+
+impl From<String> for BasicInternalError {
+    fn from(s: String) -> Self {
+        Self {
+            message: s,
+            source: None,
         }
     }
 }
 
-impl std::error::Error for BasicInternalError {}
+impl std::error::Error for BasicInternalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self.source {
+            Some(ref source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
 
 impl fmt::Display for BasicInternalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -40,6 +84,68 @@ impl fmt::Display for BasicInternalError {
     }
 }
 
+/// Builds a multi-line description from several pieces.
+///
+/// When a description is made up of several conditionally-present pieces (such as
+/// the details of the request that failed), assembling it with `format!` quickly
+/// becomes unwieldy. A [DescriptionBuilder] lets you add lines and labelled fields
+/// one at a time, and then build the description for use with any of the error
+/// helpers. Multi-line descriptions are rendered with their line breaks intact.
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, DescriptionBuilder};
+///
+/// let path = "/home/user/.config/demo.yml";
+/// let line = Some(12);
+///
+/// let mut description = DescriptionBuilder::new("We could not parse your config file.");
+/// description.field("Path", path);
+/// if let Some(line) = line {
+///   description.field("Line", line);
+/// }
+///
+/// let err = human_errors::user(
+///   &description.build(),
+///   "Make sure that your config file is valid YAML.",
+/// );
+///
+/// assert_eq!(
+///   err.description(),
+///   "We could not parse your config file.\nPath: /home/user/.config/demo.yml\nLine: 12"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DescriptionBuilder {
+    lines: Vec<String>,
+}
+
+impl DescriptionBuilder {
+    /// Creates a new [DescriptionBuilder] whose first line is `summary`.
+    pub fn new(summary: &str) -> Self {
+        Self {
+            lines: vec![summary.to_string()],
+        }
+    }
+
+    /// Adds a line of text to the description.
+    pub fn line(&mut self, text: &str) -> &mut Self {
+        self.lines.push(text.to_string());
+        self
+    }
+
+    /// Adds a line in the form `name: value` to the description.
+    pub fn field<V: fmt::Display>(&mut self, name: &str, value: V) -> &mut Self {
+        self.lines.push(format!("{}: {}", name, value));
+        self
+    }
+
+    /// Builds the description, with each line separated by a line break.
+    pub fn build(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +173,137 @@ mod tests {
             "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
         );
     }
+
+    #[test]
+    fn test_message_internal_human_error() {
+        assert_eq!(
+            system_with_internal(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                user("You got rate limited by GitHub.", "Wait a few minutes and try again.")
+            )
+            .message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again.\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_public_message() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+                detailed_message("token=abc123"),
+            ),
+        );
+
+        assert_eq!(err.public_message(), err.message());
+
+        assert_eq!(
+            system_with_internal(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                detailed_message("token=abc123")
+            )
+            .public_message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_redacted() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+                detailed_message("token=abc123"),
+            ),
+        )
+        .redacted();
+
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again.\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_downcast_internal() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+                detailed_message("HTTP 429"),
+            ),
+        );
+
+        assert_eq!(
+            err.downcast_internal::<BasicInternalError>()
+                .unwrap()
+                .to_string(),
+            "HTTP 429"
+        );
+        assert!(err.downcast_internal::<std::io::Error>().is_none());
+
+        assert!(err.is_caused_by::<BasicInternalError>());
+        assert!(!err.is_caused_by::<std::io::Error>());
+        assert!(err.is_caused_by::<Error>());
+        assert!(!user("You got rate limited by GitHub.", "").is_caused_by::<Error>());
+
+        let err = system_with_internal(
+            "Something bad happened.",
+            "",
+            user("You got rate limited by GitHub.", ""),
+        );
+        assert!(err.is_caused_by::<Error>());
+    }
+
+    #[test]
+    fn test_description_builder() {
+        let description = DescriptionBuilder::new("We could not reach GitHub.")
+            .line("The request timed out.")
+            .field("Attempts", 3)
+            .build();
+
+        assert_eq!(
+            user_with_cause(
+                "Something bad happened.",
+                "",
+                system(&description, "Wait a few minutes and try again.")
+            )
+            .message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - We could not reach GitHub.\n   The request timed out.\n   Attempts: 3\n\nTo try and fix this, you can:\n - Wait a few minutes and try again."
+        );
+
+        assert_eq!(DescriptionBuilder::default().build(), "");
+    }
+
+    #[test]
+    fn test_redact() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "Rotate the token abc123 and try again.",
+                BasicInternalError::with_source(
+                    "GET https://api.github.com/user?token=abc123",
+                    Box::new(detailed_message("HTTP 429 for abc123")),
+                ),
+            ),
+        )
+        .redact(&["abc123", ""]);
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n - GET https://api.github.com/user?token=***\n - HTTP 429 for ***\n\nTo try and fix this, you can:\n - Rotate the token *** and try again.\n - Avoid bad things happening in future"
+        );
+    }
 }
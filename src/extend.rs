@@ -1,5 +1,13 @@
 /// Create a shim error type between [`human_errors::Error`] and other error types.
 ///
+/// The generated type and helper functions are `pub` by default, but you may
+/// provide a visibility (such as `pub(crate)`) to keep them out of your public API,
+/// as well as any attributes (like doc comments) which should be applied to the type.
+///
+/// If you add `result_ext = MyResultExt`, an extension trait with that name is also
+/// generated. It mirrors [`crate::ResultExt`], but its methods return your
+/// shim type, so you can import it in place of `human_errors::ResultExt`.
+///
 /// # Examples
 /// ```
 /// human_errors::error_shim!(MyError);
@@ -14,9 +22,106 @@
 ///   }    
 /// }
 /// ```
+///
+/// ```
+/// human_errors::error_shim!(pub(crate) MyError, result_ext = MyResultExt);
+///
+/// fn load(port: &str) -> Result<u16, MyError> {
+///   port.parse::<u16>().map_err(|err| {
+///     user_with_internal("The port you provided was invalid.", "Provide a port between 1 and 65535.", err)
+///   })
+/// }
+///
+/// let err = load("http")
+///   .wrap_err_preserving_kind("We could not start the server.", "")
+///   .unwrap_err();
+/// assert!(err.is_user());
+/// ```
 #[macro_export]
 macro_rules! error_shim {
-    ($type:ident) => {
+    ($(#[$meta:meta])* $type:ident, result_ext = $ext:ident) => {
+        $crate::error_shim!($(#[$meta])* pub $type, result_ext = $ext);
+    };
+
+    ($(#[$meta:meta])* $vis:vis $type:ident, result_ext = $ext:ident) => {
+        $crate::error_shim!($(#[$meta])* $vis $type);
+
+        /// Extension methods for [Result]s whose errors can be converted into an [Error].
+        ///
+        /// These mirror the methods on `human_errors::ResultExt`, however they return
+        /// this module's error type rather than `human_errors::Error`, so no further
+        /// conversion is needed. Import this trait instead of `human_errors::ResultExt`
+        /// (importing both makes the method calls ambiguous).
+        #[allow(dead_code)]
+        $vis trait $ext<T> {
+            /// Wraps the error in a new error without changing who is to blame for it.
+            fn wrap_err_preserving_kind(self, description: &str, advice: &str) -> ::std::result::Result<T, $type>;
+
+            /// Wraps the error in a new error of the given kind.
+            fn map_err_as(
+                self,
+                kind: $crate::Kind,
+                description: &str,
+                advice: &str,
+            ) -> ::std::result::Result<T, $type>;
+
+            /// Marks a step as one which must succeed, discarding its value.
+            fn require(self, description: &str, advice: &str) -> ::std::result::Result<(), $type>;
+
+            /// Calls the provided closure with the error, without otherwise changing the result.
+            fn inspect_err_human<F>(self, f: F) -> ::std::result::Result<T, $type>
+            where
+                F: FnOnce(&$crate::Error);
+
+            /// Recovers from an error by substituting a default value, while still surfacing its advice.
+            fn or_else_advise<F>(self, default: T, render: F) -> ::std::result::Result<T, $type>
+            where
+                F: FnOnce(&$crate::Error);
+        }
+
+        impl<T, E> $ext<T> for ::std::result::Result<T, E>
+        where
+            E: Into<$crate::Error>,
+        {
+            fn wrap_err_preserving_kind(self, description: &str, advice: &str) -> ::std::result::Result<T, $type> {
+                $crate::ResultExt::wrap_err_preserving_kind(self, description, advice)
+                    .map_err(<$type>::from)
+            }
+
+            fn map_err_as(
+                self,
+                kind: $crate::Kind,
+                description: &str,
+                advice: &str,
+            ) -> ::std::result::Result<T, $type> {
+                $crate::ResultExt::map_err_as(self, kind, description, advice).map_err(<$type>::from)
+            }
+
+            fn require(self, description: &str, advice: &str) -> ::std::result::Result<(), $type> {
+                $crate::ResultExt::require(self, description, advice).map_err(<$type>::from)
+            }
+
+            fn inspect_err_human<F>(self, f: F) -> ::std::result::Result<T, $type>
+            where
+                F: FnOnce(&$crate::Error),
+            {
+                $crate::ResultExt::inspect_err_human(self, f).map_err(<$type>::from)
+            }
+
+            fn or_else_advise<F>(self, default: T, render: F) -> ::std::result::Result<T, $type>
+            where
+                F: FnOnce(&$crate::Error),
+            {
+                $crate::ResultExt::or_else_advise(self, default, render).map_err(<$type>::from)
+            }
+        }
+    };
+
+    ($(#[$meta:meta])* $type:ident) => {
+        $crate::error_shim!($(#[$meta])* pub $type);
+    };
+
+    ($(#[$meta:meta])* $vis:vis $type:ident) => {
         /// A basic error triggered by something the user has done.
         ///
         /// Constructs a new [Error] describing a failure which was the result of an
@@ -33,7 +138,7 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn user(description: &str, advice: &str) -> $type {
+        $vis fn user(description: &str, advice: &str) -> $type {
             $crate::user(description, advice).into()
         }
 
@@ -59,10 +164,40 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn user_with_cause(description: &str, advice: &str, cause: $type) -> $type {
+        $vis fn user_with_cause(description: &str, advice: &str, cause: $type) -> $type {
             $crate::user_with_cause(description, advice, cause.into()).into()
         }
 
+        /// An error triggered by something the user has done, with several deeper causes.
+        ///
+        /// Constructs a new [Error] describing a failure which was the result of an
+        /// action that the user has taken. This error includes a description of what
+        /// occurred, as well as some advice for the user to try to mitigate the problem.
+        /// Each of the `causes` is an immediate cause of this error, listed in order.
+        ///
+        /// # Examples
+        /// ```
+        /// use human_errors;
+        ///
+        /// human_errors::user_with_causes(
+        ///   "We could not find a config file.",
+        ///   "Create a config file with `app init`.",
+        ///   vec![
+        ///     human_errors::user("There was no ./app.yml file.", ""),
+        ///     human_errors::user("There was no ~/.config/app.yml file.", ""),
+        ///   ]
+        /// );
+        /// ```
+        #[allow(dead_code)]
+        $vis fn user_with_causes(description: &str, advice: &str, causes: Vec<$type>) -> $type {
+            $crate::user_with_causes(
+                description,
+                advice,
+                causes.into_iter().map(|cause| cause.into()).collect(),
+            )
+            .into()
+        }
+
         /// An error triggered by something the user has done, with a deeper cause.
         ///
         /// Constructs a new [Error] describing a failure which was the result of an
@@ -83,13 +218,47 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn user_with_internal<T>(description: &str, advice: &str, internal: T) -> $type
+        $vis fn user_with_internal<T>(description: &str, advice: &str, internal: T) -> $type
         where
             T: Into<Box<dyn std::error::Error + Send + Sync>>,
         {
             $crate::user_with_internal(description, advice, internal).into()
         }
 
+        /// An error triggered by something the user has done, with a deeper cause and additional detail.
+        ///
+        /// Constructs a new [Error] describing a failure which was the result of an
+        /// action that the user has taken. This error includes a description of what
+        /// occurred, as well as some advice for the user to try to mitigate the problem.
+        /// It also includes a low-level `detail` message, which was itself caused by the
+        /// internal error.
+        ///
+        /// **NOTE**: The internal error may be any type which may be converted into a [Box<std::error::Error>].
+        ///
+        /// # Examples
+        /// ```
+        /// use human_errors;
+        ///
+        /// human_errors::user_with_internal_detail(
+        ///   "We could not open the config file you provided.",
+        ///   "Make sure that the file exists and is readable by the application.",
+        ///   "Failed to open /home/user/.config/demo.yml",
+        ///   human_errors::detailed_message("ENOENT 2: No such file or directory")
+        /// );
+        /// ```
+        #[allow(dead_code)]
+        $vis fn user_with_internal_detail<T>(
+            description: &str,
+            advice: &str,
+            detail: &str,
+            internal: T,
+        ) -> $type
+        where
+            T: Into<Box<dyn std::error::Error + Send + Sync>>,
+        {
+            $crate::user_with_internal_detail(description, advice, detail, internal).into()
+        }
+
         /// An error triggered by the system rather than the user.
         ///
         /// Constructs a new [Error] describing a failure which was the result of a failure
@@ -106,7 +275,7 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn system(description: &str, advice: &str) -> $type {
+        $vis fn system(description: &str, advice: &str) -> $type {
             $crate::system(description, advice).into()
         }
 
@@ -132,10 +301,40 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn system_with_cause(description: &str, advice: &str, cause: $type) -> $type {
+        $vis fn system_with_cause(description: &str, advice: &str, cause: $type) -> $type {
             $crate::system_with_cause(description, advice, cause.into()).into()
         }
 
+        /// An error triggered by the system rather than the user, with several deeper causes.
+        ///
+        /// Constructs a new [Error] describing a failure which was the result of a failure
+        /// in the system, rather than a user's action. This error includes a description of what
+        /// occurred, as well as some advice for the user to try to mitigate the problem.
+        /// Each of the `causes` is an immediate cause of this error, listed in order.
+        ///
+        /// # Examples
+        /// ```
+        /// use human_errors;
+        ///
+        /// human_errors::system_with_causes(
+        ///   "We could not find a config file.",
+        ///   "Create a config file with `app init`.",
+        ///   vec![
+        ///     human_errors::system("There was no ./app.yml file.", ""),
+        ///     human_errors::system("There was no ~/.config/app.yml file.", ""),
+        ///   ]
+        /// );
+        /// ```
+        #[allow(dead_code)]
+        $vis fn system_with_causes(description: &str, advice: &str, causes: Vec<$type>) -> $type {
+            $crate::system_with_causes(
+                description,
+                advice,
+                causes.into_iter().map(|cause| cause.into()).collect(),
+            )
+            .into()
+        }
+
         /// An error triggered by the system rather than the user, with a deeper cause.
         ///
         /// Constructs a new [Error] describing a failure which was the result of a failure
@@ -156,13 +355,47 @@ macro_rules! error_shim {
         /// );
         /// ```
         #[allow(dead_code)]
-        pub fn system_with_internal<T>(description: &str, advice: &str, internal: T) -> $type
+        $vis fn system_with_internal<T>(description: &str, advice: &str, internal: T) -> $type
         where
             T: Into<Box<dyn std::error::Error + Send + Sync>>,
         {
             $crate::system_with_internal(description, advice, internal).into()
         }
 
+        /// An error triggered by the system rather than the user, with a deeper cause and additional detail.
+        ///
+        /// Constructs a new [Error] describing a failure which was the result of a failure
+        /// in the system, rather than a user's action. This error includes a description of what
+        /// occurred, as well as some advice for the user to try to mitigate the problem.
+        /// It also includes a low-level `detail` message, which was itself caused by the
+        /// internal error.
+        ///
+        /// **NOTE**: The internal error may be any type which may be converted into a [Box<std::error::Error>].
+        ///
+        /// # Examples
+        /// ```
+        /// use human_errors;
+        ///
+        /// human_errors::system_with_internal_detail(
+        ///   "We could not open the config file you provided.",
+        ///   "Make sure that the file exists and is readable by the application.",
+        ///   "Failed to open /home/user/.config/demo.yml",
+        ///   human_errors::detailed_message("ENOENT 2: No such file or directory")
+        /// );
+        /// ```
+        #[allow(dead_code)]
+        $vis fn system_with_internal_detail<T>(
+            description: &str,
+            advice: &str,
+            detail: &str,
+            internal: T,
+        ) -> $type
+        where
+            T: Into<Box<dyn std::error::Error + Send + Sync>>,
+        {
+            $crate::system_with_internal_detail(description, advice, detail, internal).into()
+        }
+
         /// The fundamental error type used by this library.
         ///
         /// An error type which encapsulates information about whether an error
@@ -181,8 +414,9 @@ macro_rules! error_shim {
         /// // Prints the error and any advice for the user.
         /// println!("{}", err)
         /// ```
+        $(#[$meta])*
         #[derive(Debug)]
-        pub struct $type($crate::Error);
+        $vis struct $type($crate::Error);
 
         impl From<$crate::Error> for $type {
             fn from(err: $crate::Error) -> Self {
@@ -197,6 +431,12 @@ macro_rules! error_shim {
             }
         }
 
+        impl AsRef<$crate::Error> for $type {
+            fn as_ref(&self) -> &$crate::Error {
+                &self.0
+            }
+        }
+
         #[allow(dead_code)]
         impl $type {
             /// Gets the description message from this error.
@@ -316,7 +556,7 @@ macro_rules! error_shim {
 
 #[cfg(test)]
 mod tests {
-    error_shim!(MyError);
+    error_shim!(MyError, result_ext = MyResultExt);
 
     impl From<std::num::ParseIntError> for MyError {
         fn from(err: std::num::ParseIntError) -> Self {
@@ -334,4 +574,83 @@ mod tests {
 
         assert_eq!(err.description(), "Something exploded.");
     }
+
+    mod restricted {
+        error_shim!(
+            /// An error which is only visible within this crate.
+            pub(crate) RestrictedError
+        );
+    }
+
+    #[test]
+    fn test_error_visibility() {
+        let err = restricted::system("Something exploded.", "Don't blow it up in future.");
+
+        assert!(err.is_system());
+    }
+
+    #[test]
+    fn test_error_as_ref() {
+        let err = system("Something exploded.", "Don't blow it up in future.");
+
+        assert_eq!(
+            crate::oneline(err.as_ref()).to_string(),
+            "[sys] Something exploded."
+        );
+    }
+
+    #[test]
+    fn test_result_ext() {
+        let result: Result<u32, std::num::ParseIntError> = "abc".parse::<u32>();
+        let err: MyError = result
+            .wrap_err_preserving_kind("We could not read your config file.", "")
+            .unwrap_err();
+
+        assert!(err.is_user());
+        assert_eq!(err.description(), "We could not read your config file.");
+
+        let result: Result<u32, MyError> = Err(user("Something exploded.", ""));
+        let err: MyError = result
+            .map_err_as(crate::Kind::System, "We could not start.", "")
+            .unwrap_err();
+
+        assert!(err.is_system());
+    }
+
+    mod with_result_alias {
+        type Result<T> = std::result::Result<T, ScopedError>;
+
+        error_shim!(pub(crate) ScopedError, result_ext = ScopedResultExt);
+
+        #[test]
+        fn test_shim_with_result_alias() {
+            let result: Result<()> = Err(user("Something exploded.", ""));
+            let err = result.require("We could not start.", "").unwrap_err();
+
+            assert_eq!(err.description(), "We could not start.");
+        }
+    }
+
+    mod alongside_result_ext {
+        use crate::ResultExt;
+
+        error_shim!(pub(crate) PlainError);
+
+        type Result<T> = std::result::Result<T, PlainError>;
+
+        fn load() -> Result<()> {
+            "abc"
+                .parse::<u32>()
+                .require("We could not read your config file.", "")
+                .map_err(PlainError::from)
+        }
+
+        #[test]
+        fn test_shim_with_crate_result_ext() {
+            let err = load().unwrap_err();
+
+            assert!(err.is_user());
+            assert_eq!(err.description(), "We could not read your config file.");
+        }
+    }
 }
@@ -0,0 +1,61 @@
+/// The fixed pieces of text used when formatting an [crate::Error]'s message.
+///
+/// [crate::Error::message] uses English text for the headings and markers in
+/// its output. When you need to localize or rebrand that output, you can provide
+/// your own [MessageFormat] to [crate::Error::message_with] instead. Any fields
+/// you don't need to change can be taken from [MessageFormat::default].
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, MessageFormat};
+///
+/// let err = human_errors::user(
+///   "Impossible d'ouvrir le fichier de configuration.",
+///   "Vérifiez que le fichier existe.",
+/// );
+///
+/// let format = MessageFormat {
+///   user_prefix: "Oups ! ",
+///   advice_heading: "Pour corriger ce problème, vous pouvez :",
+///   ..MessageFormat::default()
+/// };
+///
+/// assert_eq!(
+///   err.message_with(&format),
+///   "Oups ! Impossible d'ouvrir le fichier de configuration.\n\nPour corriger ce problème, vous pouvez :\n - Vérifiez que le fichier existe."
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageFormat<'a> {
+    /// The text shown before the description of a user error.
+    pub user_prefix: &'a str,
+    /// The text shown after the description of a user error.
+    pub user_suffix: &'a str,
+    /// The text shown before the description of a system error.
+    pub system_prefix: &'a str,
+    /// The text shown after the description of a system error.
+    pub system_suffix: &'a str,
+    /// The heading shown above the list of causes.
+    pub causes_heading: &'a str,
+    /// The heading shown above the list of advice.
+    pub advice_heading: &'a str,
+    /// The heading shown above the internal error details, when using [crate::Verbosity::Full].
+    pub details_heading: &'a str,
+    /// The marker shown at the start of each cause and piece of advice.
+    pub bullet: &'a str,
+}
+
+impl Default for MessageFormat<'static> {
+    fn default() -> Self {
+        Self {
+            user_prefix: "Oh no! ",
+            user_suffix: "",
+            system_prefix: "Whoops! ",
+            system_suffix: " (This isn't your fault)",
+            causes_heading: "This was caused by:",
+            advice_heading: "To try and fix this, you can:",
+            details_heading: "Details for bug reports:",
+            bullet: " - ",
+        }
+    }
+}
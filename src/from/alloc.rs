@@ -0,0 +1,31 @@
+use crate::catalog::translate;
+use crate::{system_with_internal, Error};
+use std::collections::TryReserveError;
+use std::convert;
+
+impl convert::From<TryReserveError> for Error {
+    fn from(err: TryReserveError) -> Self {
+        system_with_internal(
+            &translate(
+                "alloc.try_reserve.description",
+                "We could not allocate the memory needed to continue.",
+            ),
+            &translate(
+                "alloc.try_reserve.advice",
+                "The system is low on memory; close other applications or increase the available memory and try again.",
+            ),
+            err,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_reserve() {
+        let err: Error = Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err().into();
+        assert!(err.is_system());
+    }
+}
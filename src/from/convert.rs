@@ -0,0 +1,55 @@
+use crate::catalog::translate;
+use crate::{system_with_internal, user_with_internal, Error};
+use std::array::TryFromSliceError;
+use std::convert;
+use std::num::TryFromIntError;
+
+impl convert::From<TryFromSliceError> for Error {
+    fn from(err: TryFromSliceError) -> Self {
+        system_with_internal(
+            &translate(
+                "convert.slice.description",
+                "We received data which was not the length we expected.",
+            ),
+            &translate(
+                "convert.slice.advice",
+                "Check that the input you provided is complete and has not been truncated, or report this problem to us on GitHub.",
+            ),
+            err,
+        )
+    }
+}
+
+impl convert::From<TryFromIntError> for Error {
+    fn from(err: TryFromIntError) -> Self {
+        user_with_internal(
+            &translate(
+                "convert.int.description",
+                "The number you provided was outside of the range we support.",
+            ),
+            &translate(
+                "convert.int.advice",
+                "Make sure that you're providing a smaller (or non-negative) number.",
+            ),
+            err,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_try_from_slice() {
+        let err: Error = <[u8; 4]>::try_from(&[1u8, 2, 3][..]).unwrap_err().into();
+        assert!(err.is_system());
+    }
+
+    #[test]
+    fn test_try_from_int() {
+        let err: Error = u8::try_from(300u32).unwrap_err().into();
+        assert!(err.is_user());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::catalog::translate;
+use crate::{user_with_internal, Error};
+use std::convert;
+use std::ffi::{FromVecWithNulError, NulError};
+
+impl convert::From<NulError> for Error {
+    fn from(err: NulError) -> Self {
+        user_with_internal(
+            &translate(
+                "ffi.nul.description",
+                "The value you provided contained a NUL byte, which we cannot support.",
+            ),
+            &translate(
+                "ffi.nul.advice",
+                "Remove any embedded NUL bytes from the input and try again.",
+            ),
+            err,
+        )
+    }
+}
+
+impl convert::From<FromVecWithNulError> for Error {
+    fn from(err: FromVecWithNulError) -> Self {
+        user_with_internal(
+            &translate(
+                "ffi.vec_with_nul.description",
+                "The value you provided was not correctly terminated by a single NUL byte.",
+            ),
+            &translate(
+                "ffi.vec_with_nul.advice",
+                "Make sure that the input ends with a NUL byte and contains no others.",
+            ),
+            err,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_nul() {
+        let err: Error = CString::new("hello\0world").unwrap_err().into();
+        assert!(err.is_user());
+    }
+
+    #[test]
+    fn test_vec_with_nul() {
+        let err: Error = CString::from_vec_with_nul(b"hello".to_vec())
+            .unwrap_err()
+            .into();
+        assert!(err.is_user());
+    }
+}
@@ -1 +1,15 @@
+mod alloc;
+mod convert;
+mod ffi;
+mod net;
+mod parse;
+#[cfg(feature = "regex")]
+mod regex;
 mod std_io;
+mod sync;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+pub use std_io::*;
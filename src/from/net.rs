@@ -0,0 +1,35 @@
+use crate::catalog::translate;
+use crate::{user_with_internal, Error};
+use std::convert;
+use std::net::AddrParseError;
+
+impl convert::From<AddrParseError> for Error {
+    fn from(err: AddrParseError) -> Self {
+        user_with_internal(
+            &translate(
+                "net.addr_parse.description",
+                "The network address you provided was not valid.",
+            ),
+            &translate(
+                "net.addr_parse.advice",
+                "Provide an address in the form 127.0.0.1:8080 or [::1]:8080.",
+            ),
+            err,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    #[test]
+    fn test_addr_parse() {
+        let err: Error = "localhost".parse::<SocketAddr>().unwrap_err().into();
+        assert!(err.is_user());
+        assert!(err
+            .message()
+            .ends_with("Provide an address in the form 127.0.0.1:8080 or [::1]:8080."));
+    }
+}
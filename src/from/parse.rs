@@ -0,0 +1,97 @@
+use crate::catalog::translate;
+use crate::{user_with_internal, Error};
+use std::char::ParseCharError;
+use std::convert;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
+
+impl convert::From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        user_with_internal(
+            &translate(
+                "parse.int.description",
+                "The value you provided was not a valid whole number.",
+            ),
+            &translate(
+                "parse.int.advice",
+                "Provide a whole number, such as 42, without any other characters.",
+            ),
+            err,
+        )
+    }
+}
+
+impl convert::From<ParseFloatError> for Error {
+    fn from(err: ParseFloatError) -> Self {
+        user_with_internal(
+            &translate(
+                "parse.float.description",
+                "The value you provided was not a valid number.",
+            ),
+            &translate(
+                "parse.float.advice",
+                "Provide a number, such as 3.14, without any other characters.",
+            ),
+            err,
+        )
+    }
+}
+
+impl convert::From<ParseCharError> for Error {
+    fn from(err: ParseCharError) -> Self {
+        user_with_internal(
+            &translate(
+                "parse.char.description",
+                "The value you provided was not a single character.",
+            ),
+            &translate("parse.char.advice", "Provide a single character."),
+            err,
+        )
+    }
+}
+
+impl convert::From<ParseBoolError> for Error {
+    fn from(err: ParseBoolError) -> Self {
+        user_with_internal(
+            &translate(
+                "parse.bool.description",
+                "The value you provided was not a valid boolean.",
+            ),
+            &translate("parse.bool.advice", "Provide either 'true' or 'false'."),
+            err,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_int() {
+        let err: Error = "forty-two".parse::<u32>().unwrap_err().into();
+        assert!(err.is_user());
+    }
+
+    #[test]
+    fn test_parse_float() {
+        let err: Error = "pi".parse::<f64>().unwrap_err().into();
+        assert!(err.is_user());
+    }
+
+    #[test]
+    fn test_parse_char() {
+        let err: Error = "ab".parse::<char>().unwrap_err().into();
+        assert!(err.is_user());
+        assert!(err.message().ends_with(" - Provide a single character."));
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        let err: Error = "yes".parse::<bool>().unwrap_err().into();
+        assert!(err.is_user());
+        assert!(err
+            .message()
+            .ends_with(" - Provide either 'true' or 'false'."));
+    }
+}
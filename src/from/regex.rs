@@ -0,0 +1,75 @@
+use crate::catalog::translate;
+use crate::{user, DescriptionBuilder, Error};
+use std::convert;
+
+impl convert::From<regex::Error> for Error {
+    fn from(err: regex::Error) -> Self {
+        let (summary, advice) = match err {
+            regex::Error::CompiledTooBig(_) => (
+                translate(
+                    "regex.too_big.description",
+                    "The regular expression you provided was too large to use.",
+                ),
+                translate(
+                    "regex.too_big.advice",
+                    "Simplify your pattern, for example by reducing the number of repetitions it contains.",
+                ),
+            ),
+            _ => (
+                translate(
+                    "regex.syntax.description",
+                    "The regular expression you provided was not valid.",
+                ),
+                translate("regex.syntax.advice", "Check your pattern syntax."),
+            ),
+        };
+
+        // The regex error already points at the problem in the pattern, so it is shown as part of
+        // the description rather than being repeated as a cause.
+        let description = DescriptionBuilder::new(&summary)
+            .line(&err.to_string())
+            .build();
+
+        user(&description, &advice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::{Regex, RegexBuilder};
+
+    #[test]
+    #[allow(clippy::invalid_regex)]
+    fn test_syntax() {
+        let inner = Regex::new("a(b").unwrap_err();
+        let message = inner.to_string();
+        let err: Error = inner.into();
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.description(),
+            format!(
+                "The regular expression you provided was not valid.\n{}",
+                message
+            )
+        );
+        assert_eq!(err.message().matches(&message).count(), 1);
+        assert_eq!(err.cause_count(), 0);
+        assert!(err.message().ends_with(" - Check your pattern syntax."));
+    }
+
+    #[test]
+    fn test_too_big() {
+        let err: Error = RegexBuilder::new("a{1000}")
+            .size_limit(10)
+            .build()
+            .unwrap_err()
+            .into();
+
+        assert!(err.is_user());
+        assert!(err
+            .description()
+            .starts_with("The regular expression you provided was too large to use.\n"));
+    }
+}
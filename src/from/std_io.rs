@@ -1,18 +1,178 @@
+use crate::catalog::translate;
 use crate::{system_with_internal, user_with_internal, Error};
 use std::convert;
 use std::io;
 
+/// Converts an [io::Error] into an [Error], allowing you to customize its advice.
+///
+/// The conversion from [io::Error] into [Error] provides generic advice for each
+/// [io::ErrorKind]. When you know more about the context in which the error
+/// occurred, you can use this to replace the advice for specific kinds of error
+/// while falling back to the default conversion for everything else.
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, io_error_to_human};
+/// use std::io;
+///
+/// let err: human_errors::Error = io_error_to_human(io::Error::from(io::ErrorKind::NotFound))
+///   .with_advice_for(io::ErrorKind::NotFound, "Run `app init` to create a config file.")
+///   .into();
+///
+/// assert!(err.message().contains("Run `app init` to create a config file."));
+/// ```
+pub fn io_error_to_human(err: io::Error) -> IoErrorConverter {
+    IoErrorConverter {
+        error: err,
+        advice: vec![],
+    }
+}
+
+/// A customizable conversion from an [io::Error] into an [Error], created by [io_error_to_human].
+#[derive(Debug)]
+pub struct IoErrorConverter {
+    error: io::Error,
+    advice: Vec<(io::ErrorKind, String)>,
+}
+
+impl IoErrorConverter {
+    /// Uses the provided advice when the error is of the given [io::ErrorKind].
+    ///
+    /// If advice is provided for the same kind more than once, the last advice wins.
+    pub fn with_advice_for(mut self, kind: io::ErrorKind, advice: &str) -> Self {
+        self.advice.push((kind, advice.to_string()));
+        self
+    }
+}
+
+impl convert::From<IoErrorConverter> for Error {
+    fn from(converter: IoErrorConverter) -> Self {
+        let kind = converter.error.kind();
+        let advice = converter
+            .advice
+            .into_iter()
+            .rev()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, advice)| advice);
+
+        convert_io_error(converter.error, advice)
+    }
+}
+
 impl convert::From<io::Error> for Error {
     fn from(err: io::Error) -> Self {
-        match err.kind() {
-            io::ErrorKind::NotFound => user_with_internal(
-                "Could not find the requested file.", 
-                "Check that the file path you provided is correct and try again.",
-                err),
-            _ => system_with_internal(
-                "An internal error occurred which we could not recover from.",
-                "Please read the internal error below and decide if there is something you can do to fix the problem, or report it to us on GitHub.", 
-                err)
+        convert_io_error(err, None)
+    }
+}
+
+/// Wraps an [Error] in an [io::Error], so that it can be returned from `io`-typed interfaces.
+///
+/// The [io::ErrorKind] of the resulting error is taken from the first [io::Error] found in
+/// the error's causal chain (see [Error::downcast_internal]), falling back to
+/// [io::ErrorKind::Other] when there is none. The original [Error] can be recovered using
+/// [io::Error::get_ref] or [io::Error::into_inner] and downcasting.
+impl convert::From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        let kind = err
+            .downcast_internal::<io::Error>()
+            .map(|err| err.kind())
+            .unwrap_or(io::ErrorKind::Other);
+
+        io::Error::new(kind, err)
+    }
+}
+
+fn convert_io_error(err: io::Error, advice: Option<String>) -> Error {
+    match err.kind() {
+        io::ErrorKind::NotFound => user_with_internal(
+            &translate("io.not_found.description", "Could not find the requested file."),
+            &advice.unwrap_or_else(|| translate("io.not_found.advice", "Check that the file path you provided is correct and try again.")),
+            err),
+        io::ErrorKind::TimedOut | io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset => user_with_internal(
+            &translate("io.connection.description", "We could not communicate with the remote server."),
+            &advice.unwrap_or_else(|| translate("io.connection.advice", "Check your network connection and the server address, then try again.")),
+            err),
+        io::ErrorKind::BrokenPipe => user_with_internal(
+            &translate("io.broken_pipe.description", "The program receiving our output stopped reading it before we were finished."),
+            &advice.unwrap_or_else(|| translate("io.broken_pipe.advice", "If you are piping our output into another command, make sure that it reads all of its input.")),
+            err),
+        _ => system_with_internal(
+            &translate("io.other.description", "An internal error occurred which we could not recover from."),
+            &advice.unwrap_or_else(|| translate("io.other.advice", "Please read the internal error below and decide if there is something you can do to fix the problem, or report it to us on GitHub.")),
+            err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_advice_for() {
+        let err: Error = io_error_to_human(io::Error::from(io::ErrorKind::TimedOut))
+            .with_advice_for(io::ErrorKind::NotFound, "Create the file.")
+            .with_advice_for(io::ErrorKind::TimedOut, "Check that the server is online.")
+            .into();
+
+        assert!(err.is_user());
+        assert!(err
+            .message()
+            .ends_with("To try and fix this, you can:\n - Check that the server is online."));
+
+        let err: Error = io_error_to_human(io::Error::from(io::ErrorKind::NotFound))
+            .with_advice_for(io::ErrorKind::TimedOut, "Check that the server is online.")
+            .into();
+
+        assert!(err.is_user());
+        assert!(err.message().ends_with(
+            "To try and fix this, you can:\n - Check that the file path you provided is correct and try again."
+        ));
+    }
+
+    #[test]
+    fn test_network_errors() {
+        for kind in [
+            io::ErrorKind::TimedOut,
+            io::ErrorKind::ConnectionRefused,
+            io::ErrorKind::ConnectionReset,
+        ] {
+            let err: Error = io::Error::from(kind).into();
+            assert!(err.is_user());
+            assert_eq!(
+                err.description(),
+                "We could not communicate with the remote server."
+            );
         }
+
+        let err: Error = io::Error::from(io::ErrorKind::BrokenPipe).into();
+        assert!(err.is_user());
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let err: Error = io::Error::from(io::ErrorKind::TimedOut).into();
+        assert!(err.is_retryable());
+
+        let err: Error = io::Error::from(io::ErrorKind::NotFound).into();
+        assert!(!err.is_retryable());
+
+        let err = crate::user("Something bad happened.", "");
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_into_io_error() {
+        let err: Error = io::Error::from(io::ErrorKind::NotFound).into();
+        let err: io::Error = err.into();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let inner = err.get_ref().and_then(|err| err.downcast_ref::<Error>());
+        assert_eq!(
+            inner.map(|err| err.description()),
+            Some("Could not find the requested file.".to_string())
+        );
+
+        let err: io::Error = crate::user("Something bad happened.", "").into();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
     }
 }
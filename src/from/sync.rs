@@ -0,0 +1,43 @@
+use crate::catalog::translate;
+use crate::{detailed_message, system_with_internal, Error};
+use std::convert;
+use std::sync::PoisonError;
+
+impl<T> convert::From<PoisonError<T>> for Error {
+    fn from(err: PoisonError<T>) -> Self {
+        // The poison error holds the lock's guard, which is usually not `Send`,
+        // so we only keep its message.
+        system_with_internal(
+            &translate(
+                "sync.poison.description",
+                "A part of the application stopped working after it crashed while holding a lock.",
+            ),
+            &translate(
+                "sync.poison.advice",
+                "Restart the application, and report the crash to us on GitHub along with any output that preceded it.",
+            ),
+            detailed_message(err.to_string()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_poison() {
+        let lock = Arc::new(Mutex::new(0));
+        let poisoner = lock.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poisoning the lock");
+        })
+        .join();
+
+        let err: Error = lock.lock().unwrap_err().into();
+        assert!(err.is_system());
+        assert!(err.message().contains("poisoned lock"));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::catalog::translate;
+use crate::{user, DescriptionBuilder, Error};
+use std::convert;
+
+// `toml::de::Error` does not expose the line and column of the problem directly (only its byte
+// offsets), so they are taken from the position at the start of its rendered message. The
+// message is shown as part of the description rather than being repeated as a cause.
+impl convert::From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        let mut description = DescriptionBuilder::new(&translate(
+            "toml.description",
+            "The TOML document you provided was not valid.",
+        ));
+        description.line(err.message().trim_end());
+
+        if let Some((line, column)) = position(&err.to_string()) {
+            description.field("Line", line).field("Column", column);
+        }
+
+        user(
+            &description.build(),
+            &translate(
+                "toml.advice",
+                "Make sure that the document is valid TOML and that each value has the expected type.",
+            ),
+        )
+    }
+}
+
+// Parses the position from the first line of a rendered error, which looks like
+// `TOML parse error at line 2, column 8`.
+fn position(rendered: &str) -> Option<(usize, usize)> {
+    let (_, position) = rendered.lines().next()?.split_once(" at line ")?;
+    let (line, column) = position.split_once(", column ")?;
+
+    Some((line.trim().parse().ok()?, column.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml() {
+        let inner = toml::from_str::<toml::Table>("name = \"demo\"\nport = \n").unwrap_err();
+        let message = inner.message().trim_end().to_string();
+        let err: Error = inner.into();
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.description(),
+            format!(
+                "The TOML document you provided was not valid.\n{}\nLine: 2\nColumn: 8",
+                message
+            )
+        );
+        assert_eq!(err.message().matches(&message).count(), 1);
+        assert_eq!(err.cause_count(), 0);
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(
+            position("TOML parse error at line 2, column 8\n  |\n"),
+            Some((2, 8))
+        );
+        assert_eq!(position("invalid type: string, expected a map"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::catalog::translate;
+use crate::{user, DescriptionBuilder, Error};
+use std::convert;
+
+// The message of a `serde_norway::Error` already includes the line and column of the problem (when
+// it is known), so it is shown as part of the description rather than being repeated as a cause.
+impl convert::From<serde_norway::Error> for Error {
+    fn from(err: serde_norway::Error) -> Self {
+        let description = DescriptionBuilder::new(&translate(
+            "yaml.description",
+            "The YAML document you provided was not valid.",
+        ))
+        .line(&err.to_string())
+        .build();
+
+        user(
+            &description,
+            &translate(
+                "yaml.advice",
+                "Make sure that the document is valid YAML and that each value has the expected type.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yaml() {
+        let inner = serde_norway::from_str::<serde_norway::Mapping>("name: demo\nport: [8080\n")
+            .unwrap_err();
+        let message = inner.to_string();
+        let err: Error = inner.into();
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.description(),
+            format!("The YAML document you provided was not valid.\n{}", message)
+        );
+        assert!(message.contains(" at line 3 column 1"));
+        assert_eq!(err.message().matches(" at line 3 column 1").count(), 1);
+        assert_eq!(err.cause_count(), 0);
+    }
+}
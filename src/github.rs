@@ -0,0 +1,134 @@
+use super::{Advisory, Error, Kind};
+use std::fmt;
+
+/// Renders an error or advisory as a GitHub Actions workflow command.
+///
+/// When your tool runs as part of a GitHub Actions workflow, writing this to
+/// standard output causes the error to be shown as an annotation on the run (and
+/// inline on pull requests). [Error]s are emitted as `::error` commands, while
+/// [Advisory] notices are emitted as `::warning` commands. The description and
+/// advice are included in the annotation's message, and any characters which
+/// GitHub treats specially are escaped.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+/// );
+///
+/// assert_eq!(
+///   human_errors::github_annotation(&err).to_string(),
+///   "::error title=User error::We could not open the config file you provided.%0A%0ATo try and fix this, you can:%0A - Make sure that the file exists and is readable by the application."
+/// );
+/// ```
+pub fn github_annotation<'a, T>(item: &'a T) -> GitHubAnnotation
+where
+    &'a T: Into<GitHubAnnotation>,
+{
+    item.into()
+}
+
+/// A GitHub Actions workflow command, created by [github_annotation].
+#[derive(Debug, Clone)]
+pub struct GitHubAnnotation {
+    command: &'static str,
+    title: &'static str,
+    message: String,
+}
+
+impl From<&Error> for GitHubAnnotation {
+    fn from(err: &Error) -> Self {
+        let mut message = err.description();
+        let advice = err.advice_entries();
+        if !advice.is_empty() {
+            message.push_str("\n\nTo try and fix this, you can:");
+            for advice in advice {
+                message.push_str("\n - ");
+                message.push_str(&advice);
+            }
+        }
+
+        Self {
+            command: "error",
+            title: match err.kind() {
+                Kind::User => "User error",
+                Kind::System => "System error",
+            },
+            message,
+        }
+    }
+}
+
+impl From<&Advisory> for GitHubAnnotation {
+    fn from(advisory: &Advisory) -> Self {
+        let mut message = advisory.description().to_string();
+        if !advisory.advice().is_empty() {
+            message.push_str("\n\nYou may want to:\n - ");
+            message.push_str(advisory.advice());
+        }
+
+        Self {
+            command: "warning",
+            title: "Note",
+            message,
+        }
+    }
+}
+
+impl fmt::Display for GitHubAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "::{} title={}::{}",
+            self.command,
+            escape_property(self.title),
+            escape_data(&self.message)
+        )
+    }
+}
+
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_github_annotation() {
+        assert_eq!(
+            github_annotation(&system_with_internal(
+                "We used 100% of the disk.",
+                "",
+                detailed_message("ENOSPC")
+            ))
+            .to_string(),
+            "::error title=System error::We used 100%25 of the disk."
+        );
+
+        assert_eq!(
+            github_annotation(&advisory(
+                "The --config-dir flag has been deprecated.",
+                "Use the --config flag instead."
+            ))
+            .to_string(),
+            "::warning title=Note::The --config-dir flag has been deprecated.%0A%0AYou may want to:%0A - Use the --config flag instead."
+        );
+    }
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!(escape_property("a:b,c%\r\n"), "a%3Ab%2Cc%25%0D%0A");
+    }
+}
@@ -1,4 +1,6 @@
+use super::BasicInternalError;
 pub use super::Error;
+use super::Kind;
 use std::error;
 
 /// A basic error triggered by something the user has done.
@@ -50,6 +52,33 @@ pub fn user_with_cause(description: &str, advice: &str, cause: Error) -> Error {
     )
 }
 
+/// An error triggered by something the user has done, with several deeper causes.
+///
+/// Constructs a new [Error] describing a failure which was the result of an
+/// action that the user has taken. This error includes a description of what
+/// occurred, as well as some advice for the user to try to mitigate the problem.
+/// Each of the `causes` is an immediate cause of this error, rather than a cause of
+/// the one before it, and they are listed in order (along with any advice they
+/// provide) when the error is shown. This is useful when several attempts have been
+/// made and all of them failed.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::user_with_causes(
+///   "We could not find a config file.",
+///   "Create a config file with `app init`.",
+///   vec![
+///     human_errors::user("There was no ./app.yml file.", ""),
+///     human_errors::user("There was no ~/.config/app.yml file.", ""),
+///   ]
+/// );
+/// ```
+pub fn user_with_causes(description: &str, advice: &str, causes: Vec<Error>) -> Error {
+    Error::from_causes(Kind::User, description, advice, causes)
+}
+
 /// An error triggered by something the user has done, with a deeper cause.
 ///
 /// Constructs a new [Error] describing a failure which was the result of an
@@ -81,6 +110,58 @@ where
     )
 }
 
+/// An error triggered by something the user has done, with a deeper cause and additional detail.
+///
+/// Constructs a new [Error] describing a failure which was the result of an
+/// action that the user has taken. This error includes a description of what
+/// occurred, as well as some advice for the user to try to mitigate the problem.
+/// It also includes a low-level `detail` message, which was itself caused by the
+/// internal error, allowing you to annotate an error from another library with
+/// extra context without adding another layer of advice.
+///
+/// The resulting causal chain lists the `detail` first, followed by the internal error
+/// (and any of its sources).
+///
+/// **NOTE**: The internal error may be any type which may be converted into a [Box<std::error::Error>].
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user_with_internal_detail(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+///   "Failed to open /home/user/.config/demo.yml",
+///   std::io::Error::from(std::io::ErrorKind::NotFound),
+/// );
+///
+/// // Prints a message like the following:
+/// // Oh no! We could not open the config file you provided.
+/// //
+/// // This was caused by:
+/// //  - Failed to open /home/user/.config/demo.yml
+/// //  - entity not found
+/// //
+/// // To try and fix this, you can:
+/// //  - Make sure that the file exists and is readable by the application.
+/// println!("{}", err);
+/// ```
+pub fn user_with_internal_detail<T>(
+    description: &str,
+    advice: &str,
+    detail: &str,
+    internal: T,
+) -> Error
+where
+    T: Into<Box<dyn error::Error + Send + Sync>>,
+{
+    user_with_internal(
+        description,
+        advice,
+        BasicInternalError::with_source(detail, internal.into()),
+    )
+}
+
 /// An error triggered by the system rather than the user.
 ///
 /// Constructs a new [Error] describing a failure which was the result of a failure
@@ -130,6 +211,33 @@ pub fn system_with_cause(description: &str, advice: &str, cause: Error) -> Error
     )
 }
 
+/// An error triggered by the system rather than the user, with several deeper causes.
+///
+/// Constructs a new [Error] describing a failure which was the result of a failure
+/// in the system, rather than a user's action. This error includes a description of what
+/// occurred, as well as some advice for the user to try to mitigate the problem.
+/// Each of the `causes` is an immediate cause of this error, rather than a cause of
+/// the one before it, and they are listed in order (along with any advice they
+/// provide) when the error is shown. This is useful when several attempts have been
+/// made and all of them failed.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::system_with_causes(
+///   "We could not reach any of the configured mirrors.",
+///   "Check your network connection and try again.",
+///   vec![
+///     human_errors::system("mirror-1.example.com did not respond.", ""),
+///     human_errors::system("mirror-2.example.com did not respond.", ""),
+///   ]
+/// );
+/// ```
+pub fn system_with_causes(description: &str, advice: &str, causes: Vec<Error>) -> Error {
+    Error::from_causes(Kind::System, description, advice, causes)
+}
+
 /// An error triggered by the system rather than the user, with a deeper cause.
 ///
 /// Constructs a new [Error] describing a failure which was the result of a failure
@@ -161,6 +269,91 @@ where
     )
 }
 
+/// An error triggered by the system rather than the user, with a deeper cause and additional detail.
+///
+/// Constructs a new [Error] describing a failure which was the result of a failure
+/// in the system, rather than a user's action. This error includes a description of what
+/// occurred, as well as some advice for the user to try to mitigate the problem.
+/// It also includes a low-level `detail` message, which was itself caused by the
+/// internal error, allowing you to annotate an error from another library with
+/// extra context without adding another layer of advice.
+///
+/// The resulting causal chain lists the `detail` first, followed by the internal error
+/// (and any of its sources).
+///
+/// **NOTE**: The internal error may be any type which may be converted into a [Box<std::error::Error>].
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::system_with_internal_detail(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+///   "Failed to open /home/user/.config/demo.yml",
+///   std::io::Error::from(std::io::ErrorKind::NotFound),
+/// );
+///
+/// // Prints a message like the following:
+/// // Whoops! We could not open the config file you provided. (This isn't your fault)
+/// //
+/// // This was caused by:
+/// //  - Failed to open /home/user/.config/demo.yml
+/// //  - entity not found
+/// //
+/// // To try and fix this, you can:
+/// //  - Make sure that the file exists and is readable by the application.
+/// println!("{}", err);
+/// ```
+pub fn system_with_internal_detail<T>(
+    description: &str,
+    advice: &str,
+    detail: &str,
+    internal: T,
+) -> Error
+where
+    T: Into<Box<dyn error::Error + Send + Sync>>,
+{
+    system_with_internal(
+        description,
+        advice,
+        BasicInternalError::with_source(detail, internal.into()),
+    )
+}
+
+/// Formats a sequence of steps as a single piece of numbered advice.
+///
+/// Advice is normally presented as a list of independent suggestions, any of which
+/// may help the user. When a fix requires several steps to be followed in order,
+/// you can use this to combine them into a single piece of advice which is shown
+/// as a numbered list, keeping the steps together and in the order given.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user(
+///   "The service is using an outdated config file.",
+///   &human_errors::procedure(&[
+///     "Stop the service.",
+///     "Run `app migrate` to update the config file.",
+///     "Start the service again.",
+///   ]),
+/// );
+///
+/// assert!(err.message().ends_with(
+///   " - 1. Stop the service.\n   2. Run `app migrate` to update the config file.\n   3. Start the service again."
+/// ));
+/// ```
+pub fn procedure(steps: &[&str]) -> String {
+    steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| format!("{}. {}", i + 1, step))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +435,648 @@ mod tests {
             "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again."
         );
     }
+
+    #[test]
+    fn test_message_multiline_cause() {
+        assert_eq!(
+            user_with_cause(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                user("You got rate limited by GitHub.\nYour limit resets in 5 minutes.", "")
+            )
+            .message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n   Your limit resets in 5 minutes.\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_contains_kind() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            user(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+            ),
+        );
+
+        assert!(err.is_system());
+        assert!(!err.is_user());
+        assert!(err.contains_system());
+        assert!(err.contains_user());
+        assert!(err.contains_kind(crate::Kind::System));
+        assert!(err.contains_kind(crate::Kind::User));
+
+        let err = user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        );
+        assert!(err.contains_user());
+        assert!(!err.contains_system());
+        assert!(!err.contains_kind(crate::Kind::System));
+    }
+
+    #[test]
+    fn test_kind_as_key() {
+        let errors = [
+            user(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+            ),
+            system(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+            ),
+            user(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+            ),
+        ];
+
+        let mut counts = std::collections::HashMap::new();
+        for err in errors.iter() {
+            *counts.entry(err.kind()).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts[&crate::Kind::User], 2);
+        assert_eq!(counts[&crate::Kind::System], 1);
+    }
+
+    #[test]
+    fn test_with_cause() {
+        let err = user(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+        )
+        .with_cause(user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        ))
+        .with_cause(system("The rate limit header was invalid.", ""));
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n - The rate limit header was invalid.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again.\n - Avoid bad things happening in future"
+        );
+
+        let err = user_with_internal(
+            "Something bad happened.",
+            "",
+            crate::detailed_message("HTTP 429 Too Many Requests"),
+        )
+        .with_cause(user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        ))
+        .with_cause(system("The rate limit header was invalid.", ""));
+
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - HTTP 429 Too Many Requests\n - You got rate limited by GitHub.\n - The rate limit header was invalid.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again."
+        );
+        assert!(err.contains_system());
+        assert_eq!(err.immediate_cause().map(|cause| cause.description()), None);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let err = user("Something bad happened.", "");
+        assert!(!err.has_cause());
+        assert!(err.advice_is_empty());
+
+        let err = system_with_cause(
+            "Something bad happened.",
+            "",
+            user(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+            ),
+        );
+        assert!(err.has_cause());
+        assert!(!err.advice_is_empty());
+    }
+
+    #[test]
+    fn test_message_internal_detail() {
+        assert_eq!(
+            system_with_internal_detail(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                "Failed to fetch https://api.github.com/user",
+                crate::detailed_message("You got rate limited")
+            )
+            .message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - Failed to fetch https://api.github.com/user\n - You got rate limited\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_map_advice() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "",
+                user("Your token has expired.", "Generate a new token."),
+            ),
+        )
+        .map_advice(|advice| advice.to_uppercase());
+
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n - Your token has expired.\n\nTo try and fix this, you can:\n - GENERATE A NEW TOKEN.\n - AVOID BAD THINGS HAPPENING IN FUTURE"
+        );
+    }
+
+    #[test]
+    fn test_map_advice_through_sources() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "",
+            crate::BasicInternalError::with_source(
+                "You got rate limited by GitHub.",
+                Box::new(user("Your token has expired.", "Generate a new token.")),
+            ),
+        )
+        .map_advice(|advice| advice.to_uppercase());
+
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited by GitHub.\n - Your token has expired.\n\nTo try and fix this, you can:\n - GENERATE A NEW TOKEN."
+        );
+        assert_eq!(err.advice_entries(), vec!["GENERATE A NEW TOKEN."]);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "",
+            system_with_cause(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                user(
+                    "You got rate limited by GitHub.",
+                    "Wait a few minutes and try again.",
+                ),
+            ),
+        )
+        .flatten();
+
+        assert!(err.is_system());
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again.\n - Avoid bad things happening in future"
+        );
+
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Try again later.",
+            user(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+            ),
+        )
+        .flatten();
+
+        assert!(err.has_cause());
+        assert_eq!(err.advice_count(), 2);
+
+        let err = Error::UserError(
+            "Something bad happened.".to_string(),
+            String::new(),
+            Some(Box::new(system_with_internal(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                crate::detailed_message("HTTP 429 Too Many Requests"),
+            ))),
+            Some(crate::detailed_message("GET https://api.github.com/user").into()),
+        )
+        .flatten();
+
+        assert!(!err.source_is_human());
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - GET https://api.github.com/user\n - HTTP 429 Too Many Requests\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_counts() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "",
+                crate::detailed_message("HTTP 429"),
+            ),
+        );
+
+        assert_eq!(err.advice_count(), 1);
+        assert_eq!(err.cause_count(), 2);
+
+        let err = user("Something bad happened.", "");
+        assert_eq!(err.advice_count(), 0);
+        assert_eq!(err.cause_count(), 0);
+    }
+
+    #[test]
+    fn test_message_with() {
+        let err = system_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            user("You got rate limited\nby GitHub.", ""),
+        );
+
+        assert_eq!(
+            err.message_with(&crate::MessageFormat::default()),
+            err.message()
+        );
+
+        let format = crate::MessageFormat {
+            system_prefix: "",
+            system_suffix: "",
+            causes_heading: "Causes:",
+            advice_heading: "Advice:",
+            bullet: "* ",
+            ..crate::MessageFormat::default()
+        };
+
+        assert_eq!(
+            err.message_with(&format),
+            "Something bad happened.\n\nCauses:\n* You got rate limited\n  by GitHub.\n\nAdvice:\n* Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_message_without_advice() {
+        let err = system_with_cause(
+            "Failed to generate config file.",
+            "",
+            system_with_internal(
+                "The config template was missing.",
+                "",
+                crate::detailed_message("ENOENT"),
+            ),
+        );
+
+        assert!(err.advice_is_empty());
+        assert_eq!(
+            err.message(),
+            "Whoops! Failed to generate config file. (This isn't your fault)\n\nThis was caused by:\n - The config template was missing.\n - ENOENT"
+        );
+        assert_eq!(
+            err.public_message(),
+            "Whoops! Failed to generate config file. (This isn't your fault)"
+        );
+
+        let mut output = Vec::new();
+        crate::render_to(&err, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            err.message_at(
+                &crate::MessageFormat::default(),
+                crate::verbosity::render_verbosity()
+            )
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_cause(
+                "You got rate limited by GitHub.",
+                "",
+                user("Your token has expired.", "Generate a new token."),
+            ),
+        );
+
+        assert_eq!(
+            err.report(),
+            vec![
+                (
+                    "Something bad happened.".to_string(),
+                    vec!["Avoid bad things happening in future"]
+                ),
+                ("You got rate limited by GitHub.".to_string(), vec![]),
+                (
+                    "Your token has expired.".to_string(),
+                    vec!["Generate a new token."]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_causes() {
+        let err = user_with_causes(
+            "We could not find a config file.",
+            "Create a config file with `app init`.",
+            vec![
+                system_with_internal(
+                    "There was no ~/.config/app.yml file.",
+                    "Check the permissions on your home directory.",
+                    crate::detailed_message("EACCES"),
+                ),
+                user(
+                    "There was no ./app.yml file.",
+                    "Run the command from your project directory.",
+                ),
+            ],
+        );
+
+        assert!(err.is_user());
+        assert_eq!(
+            err.message(),
+            "Oh no! We could not find a config file.\n\nThis was caused by:\n - There was no ~/.config/app.yml file.\n - EACCES\n - There was no ./app.yml file.\n\nTo try and fix this, you can:\n - Check the permissions on your home directory.\n - Run the command from your project directory.\n - Create a config file with `app init`."
+        );
+        assert_eq!(
+            err.describe_chain()
+                .into_iter()
+                .map(|(kind, _)| kind)
+                .collect::<Vec<_>>(),
+            vec![Kind::User, Kind::System, Kind::System, Kind::User]
+        );
+
+        let mut depths = vec![];
+        err.walk(|depth, _, _| depths.push(depth));
+        assert_eq!(depths, vec![0, 1, 2, 1]);
+
+        assert_eq!(
+            system_with_causes("Something bad happened.", "", vec![]).message(),
+            "Whoops! Something bad happened. (This isn't your fault)"
+        );
+    }
+
+    #[test]
+    fn test_describe_chain() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "",
+            crate::BasicInternalError::with_source(
+                "Failed to fetch https://api.github.com/user",
+                Box::new(user_with_internal(
+                    "You got rate limited by GitHub.",
+                    "Wait a few minutes and try again.",
+                    crate::detailed_message("HTTP 429"),
+                )),
+            ),
+        );
+
+        assert_eq!(
+            err.describe_chain(),
+            vec![
+                (crate::Kind::System, "Something bad happened.".to_string()),
+                (
+                    crate::Kind::System,
+                    "Failed to fetch https://api.github.com/user".to_string()
+                ),
+                (
+                    crate::Kind::User,
+                    "You got rate limited by GitHub.".to_string()
+                ),
+                (crate::Kind::User, "HTTP 429".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_kind() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            user("You got rate limited by GitHub.", ""),
+        )
+        .with_kind(crate::Kind::System);
+
+        assert!(err.is_system());
+        assert!(err.contains_user());
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_advice_order() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Check your token.",
+            user_with_cause(
+                "You got rate limited by GitHub.",
+                "Wait a few minutes and try again.",
+                user_with_cause(
+                    "Your token has expired.",
+                    "Check your token.",
+                    user("The token was issued last year.", "Generate a new token."),
+                ),
+            ),
+        );
+
+        assert_eq!(
+            err.advice_entries(),
+            vec![
+                "Generate a new token.",
+                "Check your token.",
+                "Wait a few minutes and try again."
+            ]
+        );
+        assert_eq!(err.advice_count(), 3);
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n - Your token has expired.\n - The token was issued last year.\n\nTo try and fix this, you can:\n - Generate a new token.\n - Check your token.\n - Wait a few minutes and try again."
+        );
+    }
+
+    #[test]
+    fn test_advice_for_kind() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_cause(
+                "You got rate limited by GitHub.",
+                "Report this problem on GitHub.",
+                user_with_cause(
+                    "Your token has expired.",
+                    "Generate a new token.",
+                    user("The token was issued last year.", "Generate a new token."),
+                ),
+            ),
+        );
+
+        assert_eq!(
+            err.advice_for_kind(crate::Kind::User),
+            vec![
+                "Generate a new token.",
+                "Avoid bad things happening in future"
+            ]
+        );
+        assert_eq!(
+            err.advice_for_kind(crate::Kind::System),
+            vec!["Report this problem on GitHub."]
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        let err: Error = "Something bad happened.".into();
+        assert!(err.is_system());
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)"
+        );
+
+        let err: Error = format!("Something bad happened {} times.", 3).into();
+        assert!(err.is_system());
+        assert_eq!(err.description(), "Something bad happened 3 times.");
+    }
+
+    #[test]
+    fn test_procedure() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            user(
+                "You got rate limited by GitHub.",
+                &procedure(&["Wait a few minutes.", "Try again."]),
+            ),
+        );
+
+        assert_eq!(
+            err.message(),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited by GitHub.\n\nTo try and fix this, you can:\n - 1. Wait a few minutes.\n   2. Try again.\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_from_http_status() {
+        for (status, kind) in [
+            (200, crate::Kind::System),
+            (302, crate::Kind::System),
+            (400, crate::Kind::User),
+            (429, crate::Kind::User),
+            (499, crate::Kind::User),
+            (500, crate::Kind::System),
+            (503, crate::Kind::System),
+        ] {
+            assert_eq!(crate::Kind::from_http_status(status), kind);
+            assert_eq!(
+                Error::from_http_status(status, "Something bad happened.", "").kind(),
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_verbosity() {
+        let err = user_with_internal(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            crate::detailed_message("You got rate limited"),
+        );
+        let format = crate::MessageFormat::default();
+
+        assert_eq!(
+            err.message_at(&format, crate::Verbosity::Minimal),
+            "Oh no! Something bad happened.\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+        assert_eq!(
+            err.message_at(&format, crate::Verbosity::Normal),
+            "Oh no! Something bad happened.\n\nThis was caused by:\n - You got rate limited\n\nTo try and fix this, you can:\n - Avoid bad things happening in future"
+        );
+        assert_eq!(
+            err.message_at(&format, crate::Verbosity::Full),
+            format!(
+                "{}\n\nDetails for bug reports:\n - {:?}",
+                err.message_at(&format, crate::Verbosity::Normal),
+                crate::detailed_message("You got rate limited")
+            )
+        );
+
+        let err = user("Something bad happened.", "");
+        assert_eq!(
+            err.message_at(&format, crate::Verbosity::Full),
+            err.message_at(&format, crate::Verbosity::Normal)
+        );
+    }
+
+    #[test]
+    fn test_to_report() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            crate::detailed_message("You got rate limited"),
+        );
+
+        assert_eq!(
+            err.to_report(),
+            err.message_at(&crate::MessageFormat::default(), crate::Verbosity::Full)
+        );
+
+        let report = err.to_report_with_environment();
+        assert!(report.starts_with(&err.to_report()));
+        assert!(report.contains("\n\nEnvironment:\n - Operating system: "));
+        assert!(report.ends_with(&format!(
+            " - human-errors version: {}",
+            env!("CARGO_PKG_VERSION")
+        )));
+    }
+
+    #[test]
+    fn test_walk() {
+        let err = user_with_cause(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            system_with_internal(
+                "You got rate limited by GitHub.",
+                "",
+                crate::detailed_message("HTTP 429 Too Many Requests"),
+            ),
+        );
+
+        let mut layers = vec![];
+        err.walk(|depth, human, _| layers.push((depth, human.map(|err| err.description()))));
+
+        assert_eq!(
+            layers,
+            vec![
+                (0, Some("Something bad happened.".to_string())),
+                (1, Some("You got rate limited by GitHub.".to_string())),
+                (2, None),
+            ]
+        );
+        assert_eq!(layers.len(), err.describe_chain().len());
+    }
+
+    #[test]
+    fn test_immediate_cause() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "",
+            user("You got rate limited by GitHub.", ""),
+        );
+        assert!(err.source_is_human());
+        assert_eq!(
+            err.immediate_cause().map(|cause| cause.description()),
+            Some("You got rate limited by GitHub.".to_string())
+        );
+
+        let err = system_with_internal(
+            "Something bad happened.",
+            "",
+            crate::BasicInternalError::with_source(
+                "Failed to fetch https://api.github.com/user",
+                Box::new(user("You got rate limited by GitHub.", "")),
+            ),
+        );
+        assert!(!err.source_is_human());
+        assert!(err.immediate_cause().is_none());
+
+        assert!(!user("Something bad happened.", "").source_is_human());
+    }
 }
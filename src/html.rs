@@ -0,0 +1,99 @@
+use super::{Error, Kind};
+
+/// Renders the error, its causes and its advice as semantic HTML.
+///
+/// This is useful when presenting errors in a web front-end (for example, when
+/// running in the browser through WASM) where ANSI colours are not available. The
+/// markup does not include any styling of its own, instead using classes so that
+/// your page's CSS can decide how errors should look:
+///
+/// - `error` and either `error-user` or `error-system` on the containing `<div>`,
+/// - `description` on the `<p>` holding the error's description,
+/// - `causes` on the `<ul>` listing the causal chain (omitted if there are none),
+/// - `advice` on the `<ul>` listing the advice (omitted if there is none).
+///
+/// All of the text taken from the error is HTML-escaped. Line breaks within it are
+/// preserved, so you may want to style these elements with `white-space: pre-line`.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user(
+///   "We could not open <config.toml>.",
+///   "Make sure that the file exists and is readable by the application.",
+/// );
+///
+/// assert_eq!(
+///   human_errors::html(&err),
+///   "<div class=\"error error-user\"><p class=\"description\">We could not open &lt;config.toml&gt;.</p><ul class=\"advice\"><li>Make sure that the file exists and is readable by the application.</li></ul></div>"
+/// );
+/// ```
+pub fn html(error: &Error) -> String {
+    let mut html = format!(
+        "<div class=\"error {}\"><p class=\"description\">{}</p>",
+        match error.kind() {
+            Kind::User => "error-user",
+            Kind::System => "error-system",
+        },
+        escape(&error.description())
+    );
+
+    write_list(&mut html, "causes", error.cause_descriptions());
+    write_list(&mut html, "advice", error.advice_entries());
+
+    html.push_str("</div>");
+    html
+}
+
+fn write_list(html: &mut String, class: &str, items: Vec<String>) {
+    if items.is_empty() {
+        return;
+    }
+
+    html.push_str(&format!("<ul class=\"{}\">", class));
+    for item in items {
+        html.push_str(&format!("<li>{}</li>", escape(&item)));
+    }
+    html.push_str("</ul>");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_html() {
+        let err = system_with_cause(
+            "We could not save \"report.txt\".",
+            "Avoid bad things happening in future",
+            user_with_internal(
+                "You got rate limited by GitHub.",
+                "Wait & try again.",
+                detailed_message("<script>alert('hi')</script>"),
+            ),
+        );
+
+        assert_eq!(
+            html(&err),
+            "<div class=\"error error-system\"><p class=\"description\">We could not save &quot;report.txt&quot;.</p><ul class=\"causes\"><li>You got rate limited by GitHub.</li><li>&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt;</li></ul><ul class=\"advice\"><li>Wait &amp; try again.</li><li>Avoid bad things happening in future</li></ul></div>"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::{system_with_internal, user_with_internal, Error, Kind};
+use std::error;
+
+/// Describes how an existing error type should be presented to your users.
+///
+/// If you already have error types (for example, ones generated by `thiserror`),
+/// implementing this trait for them lets you convert them into an [Error] with `?`,
+/// without needing to rewrite them. The error's human description and advice are
+/// provided by this trait, while the error itself is kept as the [Error]'s internal
+/// error so that its details are shown as the cause and it can still be recovered
+/// with [Error::downcast_internal].
+///
+/// Only [AsHumanError::human_description] needs to be implemented. By default, errors have
+/// no advice and are treated as [Kind::System] errors, since an error which has not
+/// been classified is not something the user can be expected to fix.
+///
+/// # Examples
+/// ```
+/// use human_errors::{AsHumanError, Kind};
+///
+/// #[derive(Debug, thiserror::Error)]
+/// enum ConfigError {
+///     #[error("no config file found at {0}")]
+///     NotFound(String),
+///     #[error("the config template is missing")]
+///     MissingTemplate,
+/// }
+///
+/// impl AsHumanError for ConfigError {
+///     fn human_description(&self) -> String {
+///         "We could not load your config file.".to_string()
+///     }
+///
+///     fn advice(&self) -> String {
+///         match self {
+///             ConfigError::NotFound(_) => "Create a config file with `app init`.".to_string(),
+///             ConfigError::MissingTemplate => "Please reinstall the application.".to_string(),
+///         }
+///     }
+///
+///     fn kind(&self) -> Kind {
+///         match self {
+///             ConfigError::NotFound(_) => Kind::User,
+///             ConfigError::MissingTemplate => Kind::System,
+///         }
+///     }
+/// }
+///
+/// let err: human_errors::Error = ConfigError::NotFound("app.yml".to_string()).into();
+/// assert_eq!(
+///   err.message(),
+///   "Oh no! We could not load your config file.\n\nThis was caused by:\n - no config file found at app.yml\n\nTo try and fix this, you can:\n - Create a config file with `app init`."
+/// );
+/// ```
+pub trait AsHumanError: error::Error + Send + Sync + 'static {
+    /// Gets a description of what went wrong, written for your users.
+    fn human_description(&self) -> String;
+
+    /// Gets advice on how your users can resolve the problem, if there is any.
+    fn advice(&self) -> String {
+        String::new()
+    }
+
+    /// Gets whether the problem was caused by the user or the system.
+    fn kind(&self) -> Kind {
+        Kind::System
+    }
+}
+
+impl<T> From<T> for Error
+where
+    T: AsHumanError,
+{
+    fn from(err: T) -> Self {
+        let description = err.human_description();
+        let advice = err.advice();
+
+        match err.kind() {
+            Kind::User => user_with_internal(&description, &advice, err),
+            Kind::System => system_with_internal(&description, &advice, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct TemplateError;
+
+    impl fmt::Display for TemplateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "the config template is missing")
+        }
+    }
+
+    impl error::Error for TemplateError {}
+
+    impl AsHumanError for TemplateError {
+        fn human_description(&self) -> String {
+            "We could not generate your config file.".to_string()
+        }
+    }
+
+    #[test]
+    fn test_as_human_error() {
+        let err: Error = TemplateError.into();
+
+        assert!(err.is_system());
+        assert!(err.is_caused_by::<TemplateError>());
+        assert_eq!(
+            err.message(),
+            "Whoops! We could not generate your config file. (This isn't your fault)\n\nThis was caused by:\n - the config template is missing"
+        );
+    }
+}
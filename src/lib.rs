@@ -5,13 +5,49 @@
 //! resolve) a failure. Designed to make you treat recovery from failure
 //! as a fundamental part of the design process in your application.
 
+mod advisory;
+mod catalog;
+mod context;
+mod defaults;
 mod description;
 mod error;
+mod format;
 mod from;
+mod github;
 mod helpers;
+#[cfg(feature = "html")]
+mod html;
+mod human;
+mod oneline;
+mod panic;
+pub mod prelude;
+mod render;
+mod reporter;
+#[cfg(feature = "axum")]
+mod response;
+mod result;
+mod validator;
+mod verbosity;
 #[macro_use]
 mod extend;
 
+pub use advisory::*;
+pub use catalog::{clear_advice_catalog, set_advice_catalog, AdviceCatalog};
+pub use context::*;
+pub use defaults::set_default_system_advice;
 pub use description::*;
 pub use error::*;
+pub use format::*;
+pub use from::*;
+pub use github::*;
 pub use helpers::*;
+#[cfg(feature = "html")]
+pub use html::*;
+pub use human::*;
+pub use oneline::*;
+pub use panic::*;
+pub use render::*;
+pub use reporter::*;
+pub use result::*;
+pub use validator::*;
+pub use verbosity::{set_verbosity, Verbosity};
@@ -0,0 +1,174 @@
+use super::Error;
+use std::fmt;
+
+/// Renders an error and its causes on a single line.
+///
+/// Generates a compact representation of the error which is suitable for
+/// log lines, consisting of a short tag describing the kind of error, its
+/// description and the description of each of its causes. Unlike
+/// [Error::message], no advice is included and line breaks within descriptions
+/// are collapsed so that the output always fits on one line.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user_with_internal(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+///   human_errors::detailed_message("No such file or directory")
+/// );
+///
+/// assert_eq!(
+///   human_errors::oneline(&err).to_string(),
+///   "[usr] We could not open the config file you provided. <- No such file or directory"
+/// );
+/// ```
+pub fn oneline(error: &Error) -> OneLine<'_> {
+    OneLine {
+        error,
+        max_width: None,
+    }
+}
+
+/// A single-line rendering of an [Error], created by [oneline].
+pub struct OneLine<'a> {
+    error: &'a Error,
+    max_width: Option<usize>,
+}
+
+impl OneLine<'_> {
+    /// Limits the rendered line to at most `max_width` characters.
+    ///
+    /// Lines which are too long are truncated and end with `…`. Truncation always
+    /// happens on a character boundary, so multi-byte characters are never split.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors;
+    ///
+    /// let err = human_errors::user(
+    ///   "We could not open the config file you provided.",
+    ///   "Make sure that the file exists and is readable by the application.",
+    /// );
+    ///
+    /// assert_eq!(
+    ///   human_errors::oneline(&err).max_width(24).to_string(),
+    ///   "[usr] We could not open…"
+    /// );
+    /// ```
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    fn write_line<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        let tag = match self.error {
+            Error::UserError(..) => "usr",
+            Error::SystemError(..) => "sys",
+        };
+
+        write!(f, "[{}] {}", tag, single_line(&self.error.description()))?;
+
+        for cause in self.error.cause_descriptions() {
+            write!(f, " <- {}", single_line(&cause))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for OneLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max_width {
+            None => self.write_line(f),
+            Some(max_width) => {
+                let mut line = String::new();
+                self.write_line(&mut line)?;
+
+                if line.chars().count() <= max_width {
+                    f.write_str(&line)
+                } else if max_width == 0 {
+                    Ok(())
+                } else {
+                    let truncated: String = line.chars().take(max_width - 1).collect();
+                    write!(f, "{}…", truncated)
+                }
+            }
+        }
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_oneline_basic() {
+        assert_eq!(
+            oneline(&system(
+                "Something bad happened.",
+                "Avoid bad things happening in future"
+            ))
+            .to_string(),
+            "[sys] Something bad happened."
+        );
+    }
+
+    #[test]
+    fn test_oneline_causes() {
+        assert_eq!(
+            oneline(&user_with_cause(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+                system_with_internal(
+                    "You got rate limited by GitHub.",
+                    "Wait a few minutes and try again.",
+                    detailed_message("HTTP 429\nToo Many Requests")
+                )
+            ))
+            .to_string(),
+            "[usr] Something bad happened. <- You got rate limited by GitHub. <- HTTP 429 Too Many Requests"
+        );
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let err = user_with_internal(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            detailed_message("You got rate limited"),
+        );
+
+        assert_eq!(
+            format!("{:#}", err),
+            "[usr] Something bad happened. <- You got rate limited"
+        );
+        assert_eq!(format!("{}", err), err.message());
+    }
+
+    #[test]
+    fn test_oneline_max_width() {
+        let err = user("Deploying 🚀🚀🚀 failed.", "");
+
+        assert_eq!(
+            oneline(&err).max_width(100).to_string(),
+            "[usr] Deploying 🚀🚀🚀 failed."
+        );
+        assert_eq!(
+            oneline(&err).max_width(18).to_string(),
+            "[usr] Deploying 🚀…"
+        );
+        assert_eq!(oneline(&err).max_width(1).to_string(), "…");
+        assert_eq!(oneline(&err).max_width(0).to_string(), "");
+    }
+}
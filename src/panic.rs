@@ -0,0 +1,153 @@
+use super::catalog::translate;
+use super::{detailed_message, system, system_with_internal, Error};
+use std::any::Any;
+#[cfg(feature = "cli")]
+use std::ffi::OsStr;
+#[cfg(feature = "cli")]
+use std::panic::Location;
+
+/// Converts the payload of a panic into a system [Error].
+///
+/// Used to report a panic which has been caught with [std::panic::catch_unwind]
+/// (or returned from [std::thread::JoinHandle::join]) to your users. If the panic's
+/// payload is a message (as is the case for `panic!("...")`), it is included as the
+/// internal error.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let result = std::panic::catch_unwind(|| {
+///   panic!("the disk is full");
+/// });
+///
+/// let err = human_errors::from_panic(result.unwrap_err());
+/// assert!(err.is_system());
+/// ```
+pub fn from_panic(payload: Box<dyn Any + Send>) -> Error {
+    panic_error(panic_message(payload.as_ref()))
+}
+
+/// Installs a panic hook which reports panics to your users as a system [Error].
+///
+/// Replaces Rust's default panic output with the formatted [Error::message] for
+/// the panic, including its message and the location at which it occurred. This
+/// is opt-in, and you should call it once at the start of your `main` function.
+///
+/// If the `RUST_BACKTRACE` environment variable is set to anything other than `0`,
+/// the previously installed panic hook is also called after the error has been
+/// printed so that developers still have access to the backtrace.
+///
+/// This function is only available when the `cli` feature is enabled.
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// human_errors::install_panic_hook();
+/// ```
+#[cfg(feature = "cli")]
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        eprintln!("{}", hook_error(info.payload(), info.location()));
+
+        if backtrace_enabled(std::env::var_os("RUST_BACKTRACE").as_deref()) {
+            previous(info);
+        }
+    }));
+}
+
+#[cfg(feature = "cli")]
+fn hook_error(payload: &(dyn Any + Send), location: Option<&Location<'_>>) -> Error {
+    let message = panic_message(payload).unwrap_or_else(|| "the application panicked".to_string());
+    let detail = match location {
+        Some(location) => format!("{} (at {})", message, location),
+        None => message,
+    };
+
+    panic_error(Some(detail))
+}
+
+// Matches the standard library's handling of `RUST_BACKTRACE`, where `0` disables backtraces.
+#[cfg(feature = "cli")]
+fn backtrace_enabled(value: Option<&OsStr>) -> bool {
+    matches!(value, Some(value) if value != "0")
+}
+
+fn panic_error(detail: Option<String>) -> Error {
+    let description = translate(
+        "panic.description",
+        "The application encountered an unexpected problem and could not continue.",
+    );
+    let advice = translate(
+        "panic.advice",
+        "Please report this problem to us on GitHub, including the details shown here.",
+    );
+
+    match detail {
+        Some(detail) => system_with_internal(&description, &advice, detailed_message(detail)),
+        None => system(&description, &advice),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        Some(message.to_string())
+    } else {
+        payload.downcast_ref::<String>().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_panic_message() {
+        let err = from_panic(Box::new("the disk is full"));
+        assert!(err.is_system());
+        assert_eq!(
+            err.message(),
+            "Whoops! The application encountered an unexpected problem and could not continue. (This isn't your fault)\n\nThis was caused by:\n - the disk is full\n\nTo try and fix this, you can:\n - Please report this problem to us on GitHub, including the details shown here."
+        );
+
+        let err = from_panic(Box::new(format!("the disk is {}", "full")));
+        assert!(err.message().contains(" - the disk is full"));
+    }
+
+    #[test]
+    #[cfg(feature = "cli")]
+    fn test_hook_error() {
+        let location = Location::caller();
+        let err = hook_error(&"the disk is full", Some(location));
+        assert_eq!(
+            err.message(),
+            format!(
+                "Whoops! The application encountered an unexpected problem and could not continue. (This isn't your fault)\n\nThis was caused by:\n - the disk is full (at {})\n\nTo try and fix this, you can:\n - Please report this problem to us on GitHub, including the details shown here.",
+                location
+            )
+        );
+
+        let err = hook_error(&42, None);
+        assert!(err.message().contains(" - the application panicked\n"));
+        assert_eq!(err.description(), from_panic(Box::new(42)).description());
+    }
+
+    #[test]
+    #[cfg(feature = "cli")]
+    fn test_backtrace_enabled() {
+        assert!(!backtrace_enabled(None));
+        assert!(!backtrace_enabled(Some(OsStr::new("0"))));
+        assert!(backtrace_enabled(Some(OsStr::new("1"))));
+        assert!(backtrace_enabled(Some(OsStr::new("full"))));
+    }
+
+    #[test]
+    fn test_from_panic_unknown() {
+        let err = from_panic(Box::new(42));
+        assert!(err.is_system());
+        assert!(!err.message().contains("This was caused by"));
+    }
+}
@@ -0,0 +1,21 @@
+//! Brings the most commonly used types, traits and helpers into scope.
+//!
+//! # Examples
+//! ```
+//! use human_errors::prelude::*;
+//!
+//! fn parse_port(port: &str) -> Result<u16> {
+//!   port.parse::<u16>().user_context(
+//!     "The port number you provided was not valid.",
+//!     "Make sure that you provide a number between 0 and 65535.",
+//!   )
+//! }
+//!
+//! assert!(parse_port("http").unwrap_err().is_user());
+//! ```
+
+pub use crate::{
+    detailed_message, system, system_with_cause, system_with_causes, system_with_internal,
+    system_with_internal_detail, user, user_with_cause, user_with_causes, user_with_internal,
+    user_with_internal_detail, Context, Error, Kind, Result, ResultExt,
+};
@@ -0,0 +1,77 @@
+use super::Error;
+use std::io;
+
+/// Writes the formatted error and its advice to `writer`.
+///
+/// Produces the same output as [Error::message], however it is streamed
+/// into the writer piece by piece rather than first being collected into a
+/// single [String]. This is useful when writing large errors to a file or
+/// socket. Any [io::Error] returned by the writer is propagated to the caller.
+///
+/// Since this is intended for presenting errors to your users, the level of
+/// detail respects the `HUMAN_ERRORS_VERBOSITY` environment variable (see
+/// [crate::Verbosity]).
+///
+/// # Examples
+/// ```
+/// use human_errors;
+///
+/// let err = human_errors::user(
+///   "We could not open the config file you provided.",
+///   "Make sure that the file exists and is readable by the application.",
+/// );
+///
+/// let mut output = Vec::new();
+/// human_errors::render_to(&err, &mut output).expect("write to succeed");
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), err.message());
+/// ```
+pub fn render_to(error: &Error, writer: &mut dyn io::Write) -> io::Result<()> {
+    write!(
+        writer,
+        "{}",
+        error.display_at(crate::verbosity::render_verbosity())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    struct ClosedWriter;
+
+    impl io::Write for ClosedWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_render_to() {
+        let err = system_with_internal(
+            "Something bad happened.",
+            "Avoid bad things happening in future",
+            detailed_message("You got rate limited\nby GitHub"),
+        );
+
+        let mut output = Vec::new();
+        render_to(&err, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            err.message_at(
+                &MessageFormat::default(),
+                crate::verbosity::render_verbosity()
+            )
+        );
+
+        assert_eq!(
+            render_to(&err, &mut ClosedWriter).unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::{render_to, Error, Kind};
+use std::io;
+
+/// Reports errors to a writer, suppressing identical consecutive errors.
+///
+/// When an operation is retried in a loop (for example, when polling a remote
+/// service), the same error is often encountered many times in a row. Rather
+/// than printing the full message every time, a [DedupReporter] only prints an
+/// error when it differs (by kind or description) from the previous one. When a
+/// different error is eventually reported, a note is printed first to say how
+/// many times the previous error was repeated. Errors are written using [render_to],
+/// so the level of detail respects the `HUMAN_ERRORS_VERBOSITY` environment variable.
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, DedupReporter};
+///
+/// let mut reporter = DedupReporter::new();
+///
+/// for _ in 0..3 {
+///   // Only the first of these is printed to stderr.
+///   reporter.report(&human_errors::user(
+///     "We could not reach the server.",
+///     "Check your network connection."
+///   ));
+/// }
+///
+/// // Prints "(repeated 2 times)" to stderr.
+/// reporter.flush();
+/// ```
+pub struct DedupReporter<W: io::Write = io::Stderr> {
+    writer: W,
+    last: Option<(Kind, String)>,
+    repeated: usize,
+}
+
+impl DedupReporter<io::Stderr> {
+    /// Creates a new [DedupReporter] which writes to stderr.
+    pub fn new() -> Self {
+        Self::with_writer(io::stderr())
+    }
+}
+
+impl Default for DedupReporter<io::Stderr> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: io::Write> DedupReporter<W> {
+    /// Creates a new [DedupReporter] which writes to the provided writer.
+    pub fn with_writer(writer: W) -> Self {
+        Self {
+            writer,
+            last: None,
+            repeated: 0,
+        }
+    }
+
+    /// Reports an error, unless it is identical to the previously reported error.
+    ///
+    /// Like [eprintln], this is intended for showing errors to the user, so any
+    /// failure to write to the underlying writer is ignored.
+    pub fn report(&mut self, err: &Error) {
+        let key = (err.kind(), err.description());
+        if self.last.as_ref() == Some(&key) {
+            self.repeated += 1;
+            return;
+        }
+
+        self.flush();
+
+        let _ = render_to(err, &mut self.writer).and_then(|_| writeln!(self.writer));
+
+        self.last = Some(key);
+        self.repeated = 0;
+    }
+
+    /// Writes the number of times the previous error was repeated, if it was.
+    ///
+    /// This is done automatically when a different error is reported, so you only
+    /// need to call it once you are done reporting errors (for example, when a
+    /// polling loop exits) so that the final count is not lost. Afterwards, the
+    /// previous error is forgotten and will be printed again if it is reported.
+    pub fn flush(&mut self) {
+        if self.repeated > 0 {
+            let _ = writeln!(self.writer, "(repeated {} times)", self.repeated);
+        }
+
+        self.last = None;
+        self.repeated = 0;
+    }
+
+    /// Gets the underlying writer, after calling [DedupReporter::flush].
+    pub fn into_inner(mut self) -> W {
+        self.flush();
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_dedup_reporter() {
+        let mut reporter = DedupReporter::with_writer(Vec::new());
+
+        for _ in 0..3 {
+            reporter.report(&user("We could not reach the server.", ""));
+        }
+        reporter.report(&system("We could not reach the server.", ""));
+        reporter.report(&user("We could not reach the server.", ""));
+
+        assert_eq!(
+            String::from_utf8(reporter.into_inner()).unwrap(),
+            "Oh no! We could not reach the server.\n(repeated 2 times)\nWhoops! We could not reach the server. (This isn't your fault)\nOh no! We could not reach the server.\n"
+        );
+    }
+
+    #[test]
+    fn test_dedup_reporter_trailing_repeats() {
+        let mut reporter = DedupReporter::with_writer(Vec::new());
+
+        for _ in 0..3 {
+            reporter.report(&user("We could not reach the server.", ""));
+        }
+        reporter.flush();
+        reporter.report(&user("We could not reach the server.", ""));
+        reporter.report(&user("We could not reach the server.", ""));
+
+        assert_eq!(
+            String::from_utf8(reporter.into_inner()).unwrap(),
+            "Oh no! We could not reach the server.\n(repeated 2 times)\nOh no! We could not reach the server.\n(repeated 1 times)\n"
+        );
+    }
+}
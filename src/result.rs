@@ -0,0 +1,381 @@
+use super::{system_with_cause, user_with_cause, Error, Kind};
+
+/// A [std::result::Result] whose error type defaults to [Error].
+///
+/// # Examples
+/// ```
+/// fn load_config() -> human_errors::Result<String> {
+///   Err(human_errors::user(
+///     "We could not find your config file.",
+///     "Create a config file with `app init`."
+///   ))
+/// }
+///
+/// assert!(load_config().is_err());
+/// ```
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Extension methods for [Result]s whose errors can be converted into an [Error].
+pub trait ResultExt<T> {
+    /// Wraps the error in a new [Error] without changing who is to blame for it.
+    ///
+    /// Adds a new layer to the error's causal chain with the provided description
+    /// and advice. The new layer is a system error if any [Error] in the existing
+    /// chain is a system error, otherwise it is a user error. System errors take
+    /// precedence because describing a genuine system failure as the user's fault
+    /// is far less helpful than the reverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors::ResultExt;
+    ///
+    /// let result: Result<(), human_errors::Error> = Err(human_errors::system(
+    ///   "The config directory could not be created.",
+    ///   "Please file an error report on GitHub."
+    /// ));
+    ///
+    /// let err = result.wrap_err_preserving_kind(
+    ///   "We could not save your config file.",
+    ///   "Make sure that you have permission to write to your home directory."
+    /// ).unwrap_err();
+    ///
+    /// assert!(err.is_system());
+    /// ```
+    fn wrap_err_preserving_kind(self, description: &str, advice: &str) -> Result<T, Error>;
+
+    /// Wraps the error in a new [Error] of the given [Kind].
+    ///
+    /// Adds a new layer to the error's causal chain with the provided description and
+    /// advice. Unlike [ResultExt::wrap_err_preserving_kind], the kind of the new layer is
+    /// chosen by the caller, which is useful when it is only known at runtime (for
+    /// example, when it is based on an HTTP status code).
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors::{Kind, ResultExt};
+    ///
+    /// let result: Result<String, std::io::Error> = std::fs::read_to_string("/this/file/does/not/exist");
+    ///
+    /// let err = result.map_err_as(
+    ///   Kind::System,
+    ///   "We could not load the application's built-in templates.",
+    ///   "Please reinstall the application."
+    /// ).unwrap_err();
+    ///
+    /// assert!(err.is_system());
+    /// ```
+    fn map_err_as(self, kind: Kind, description: &str, advice: &str) -> Result<T, Error>;
+
+    /// Marks a step as one which must succeed, discarding its value.
+    ///
+    /// This behaves like [ResultExt::wrap_err_preserving_kind], however any value is
+    /// discarded and `Ok(())` is returned instead. It is intended for mandatory setup
+    /// steps where only the failure is interesting.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors::ResultExt;
+    ///
+    /// fn setup() -> human_errors::Result<()> {
+    ///   std::fs::create_dir_all(std::env::temp_dir()).require(
+    ///     "We could not create the application's working directory.",
+    ///     "Make sure that you have permission to write to the temporary directory."
+    ///   )?;
+    ///
+    ///   Ok(())
+    /// }
+    ///
+    /// assert!(setup().is_ok());
+    /// ```
+    fn require(self, description: &str, advice: &str) -> Result<(), Error>;
+
+    /// Calls the provided closure with the error, without otherwise changing the result.
+    ///
+    /// Converts the error into an [Error] (if it is not one already) and passes it to
+    /// `f` before returning it. This is useful for logging or recording metrics about
+    /// errors as they pass through your application, since `f` always has access to
+    /// the [Error]'s kind, description and advice.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors::ResultExt;
+    ///
+    /// let result: Result<String, std::io::Error> = std::fs::read_to_string("/this/file/does/not/exist");
+    ///
+    /// let result = result.inspect_err_human(|err| eprintln!("{:#}", err));
+    /// assert!(result.unwrap_err().is_user());
+    /// ```
+    fn inspect_err_human<F>(self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Error);
+
+    /// Recovers from an error by substituting a default value, while still surfacing its advice.
+    ///
+    /// If the result is an error, it is converted into an [Error] and passed to `render`
+    /// (which is usually responsible for showing it to the user) before `default` is
+    /// returned in its place. This formalizes the "warn and continue" pattern, so that
+    /// the user still learns why the default was used.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_errors::ResultExt;
+    ///
+    /// let config = std::fs::read_to_string("/this/file/does/not/exist")
+    ///   .or_else_advise(String::new(), |err| eprintln!("Using the default config.\n{}", err))
+    ///   .unwrap();
+    ///
+    /// assert_eq!(config, "");
+    /// ```
+    fn or_else_advise<F>(self, default: T, render: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Error);
+
+    /// Gets the value, or prints the error to stderr and exits the process.
+    ///
+    /// This is intended for use at the top of a CLI's `main` function, where there is
+    /// nothing left to do with an error other than show it to the user. On failure,
+    /// the error's message (including its advice) is written to stderr and the process
+    /// exits with a status code of `1`. The level of detail respects the
+    /// `HUMAN_ERRORS_VERBOSITY` environment variable (see [crate::Verbosity]).
+    ///
+    /// This method is only available when the `cli` feature is enabled.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use human_errors::ResultExt;
+    ///
+    /// let config = std::fs::read_to_string("config.toml").err_to_exit();
+    /// ```
+    #[cfg(feature = "cli")]
+    fn err_to_exit(self) -> T;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<Error>,
+{
+    fn wrap_err_preserving_kind(self, description: &str, advice: &str) -> Result<T, Error> {
+        self.map_err(|err| {
+            let err = err.into();
+            if err.contains_system() {
+                system_with_cause(description, advice, err)
+            } else {
+                user_with_cause(description, advice, err)
+            }
+        })
+    }
+
+    fn map_err_as(self, kind: Kind, description: &str, advice: &str) -> Result<T, Error> {
+        self.map_err(|err| match kind {
+            Kind::User => user_with_cause(description, advice, err.into()),
+            Kind::System => system_with_cause(description, advice, err.into()),
+        })
+    }
+
+    fn require(self, description: &str, advice: &str) -> Result<(), Error> {
+        self.wrap_err_preserving_kind(description, advice)
+            .map(|_| ())
+    }
+
+    fn inspect_err_human<F>(self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Error),
+    {
+        self.map_err(|err| {
+            let err = err.into();
+            f(&err);
+            err
+        })
+    }
+
+    fn or_else_advise<F>(self, default: T, render: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Error),
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => {
+                render(&err.into());
+                Ok(default)
+            }
+        }
+    }
+
+    #[cfg(feature = "cli")]
+    fn err_to_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(err) => {
+                eprintln!(
+                    "{}",
+                    err.into().display_at(crate::verbosity::render_verbosity())
+                );
+                std::process::exit(1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_wrap_err_preserving_kind() {
+        let result: Result<(), Error> = Err(user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        ));
+        let err = result
+            .wrap_err_preserving_kind(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+            )
+            .unwrap_err();
+        assert!(err.is_user());
+
+        let result: Result<(), Error> = Err(user_with_cause(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+            system("The rate limit header was invalid.", ""),
+        ));
+        let err = result
+            .wrap_err_preserving_kind(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+            )
+            .unwrap_err();
+        assert!(err.is_system());
+        assert_eq!(
+            err.message(),
+            "Whoops! Something bad happened. (This isn't your fault)\n\nThis was caused by:\n - You got rate limited by GitHub.\n - The rate limit header was invalid.\n\nTo try and fix this, you can:\n - Wait a few minutes and try again.\n - Avoid bad things happening in future"
+        );
+    }
+
+    #[test]
+    fn test_single_advice_block() {
+        let result: Result<(), Error> = Err(user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        ));
+
+        let err = result
+            .wrap_err_preserving_kind("We could not fetch your repositories.", "")
+            .wrap_err_preserving_kind(
+                "Something bad happened.",
+                "Avoid bad things happening in future",
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.message()
+                .matches("To try and fix this, you can:")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_inspect_err_human() {
+        let mut inspected = None;
+        let result: Result<(), Error> = Err(user(
+            "You got rate limited by GitHub.",
+            "Wait a few minutes and try again.",
+        ));
+
+        let err = result
+            .inspect_err_human(|err| inspected = Some(err.kind()))
+            .unwrap_err();
+
+        assert_eq!(inspected, Some(Kind::User));
+        assert_eq!(err.description(), "You got rate limited by GitHub.");
+
+        let result: Result<(), Error> = Ok(());
+        assert!(result
+            .inspect_err_human(|_| panic!("should not be called"))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_or_else_advise() {
+        let mut rendered = None;
+        let result: Result<i32, Error> = Err(user(
+            "We could not read your config file.",
+            "Make sure that the file exists.",
+        ));
+        assert_eq!(
+            result
+                .or_else_advise(42, |err| rendered = Some(err.message()))
+                .unwrap(),
+            42
+        );
+        assert_eq!(
+            rendered.as_deref(),
+            Some("Oh no! We could not read your config file.\n\nTo try and fix this, you can:\n - Make sure that the file exists.")
+        );
+
+        let result: Result<i32, Error> = Ok(1);
+        assert_eq!(
+            result
+                .or_else_advise(42, |_| panic!("render should not be called"))
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_map_err_as() {
+        for kind in [Kind::User, Kind::System] {
+            let result: Result<(), Error> = Err(user("Your token has expired.", ""));
+            let err = result
+                .map_err_as(kind, "We could not contact GitHub.", "")
+                .unwrap_err();
+
+            assert_eq!(err.kind(), kind);
+            assert!(err.has_cause());
+        }
+    }
+
+    #[test]
+    fn test_require() {
+        let result: Result<u32, Error> = Ok(42);
+        assert!(result.require("We could not start.", "").is_ok());
+
+        let result: Result<u32, Error> = Err(system("The disk is full.", ""));
+        let err = result.require("We could not start.", "").unwrap_err();
+        assert!(err.is_system());
+        assert_eq!(err.description(), "We could not start.");
+    }
+
+    #[test]
+    fn test_unit_results() {
+        let ok = || -> Result<(), Error> { Ok(()) };
+        let fail = || -> Result<(), Error> { Err(user("Your token has expired.", "")) };
+
+        assert!(ok()
+            .wrap_err_preserving_kind("We could not start.", "")
+            .is_ok());
+        assert!(fail()
+            .wrap_err_preserving_kind("We could not start.", "")
+            .unwrap_err()
+            .is_user());
+
+        assert!(ok()
+            .map_err_as(Kind::System, "We could not start.", "")
+            .is_ok());
+        assert!(fail()
+            .map_err_as(Kind::System, "We could not start.", "")
+            .unwrap_err()
+            .is_system());
+
+        assert!(ok().require("We could not start.", "").is_ok());
+        assert!(fail().require("We could not start.", "").is_err());
+
+        assert!(ok().inspect_err_human(|_| {}).is_ok());
+        assert!(fail().inspect_err_human(|_| {}).is_err());
+
+        assert!(ok().or_else_advise((), |_| {}).is_ok());
+        assert!(fail().or_else_advise((), |_| {}).is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::catalog::translate;
+use crate::{user, Error, Kind};
+
+/// Collects the failures from a series of checks into a single [Error].
+///
+/// When validating user input, such as a configuration file or form, it is
+/// far more helpful to report every problem at once than to stop at the first.
+/// A [Validator] records each failed check and, once you are done, produces a
+/// single user error which lists all of them along with their advice.
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, Validator};
+///
+/// let port = 0;
+/// let name = "";
+///
+/// let mut v = Validator::new();
+/// v.check(port > 0, "The port you provided was invalid.", "Provide a port between 1 and 65535.");
+/// v.check(!name.is_empty(), "You did not provide a name.", "Provide a name using --name.");
+///
+/// let err = v.finish().unwrap_err();
+/// assert!(err.is_user());
+/// assert!(err.message().contains(" - You did not provide a name."));
+/// ```
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: Vec<Error>,
+}
+
+impl Validator {
+    /// Creates a new [Validator] with no failures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a failure with the given description and advice if `condition` is false.
+    pub fn check(&mut self, condition: bool, description: &str, advice: &str) -> &mut Self {
+        if !condition {
+            self.errors.push(user(description, advice));
+        }
+
+        self
+    }
+
+    /// Records a pre-built [Error] as a failure.
+    pub fn push<E: Into<Error>>(&mut self, err: E) -> &mut Self {
+        self.errors.push(err.into());
+        self
+    }
+
+    /// Gets whether every check so far has passed.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Completes validation, returning an [Error] if any of the checks failed.
+    ///
+    /// If only a single check failed, its error is returned as-is. Otherwise a
+    /// user error is returned whose causes are each of the failures, in the
+    /// order they were recorded. The failures are listed side by side rather
+    /// than as causes of one another, and their advice is also listed in the
+    /// order they were recorded.
+    pub fn finish(self) -> Result<(), Error> {
+        let mut errors = self.errors;
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Error::from_causes(
+                Kind::User,
+                &translate(
+                    "validate.description",
+                    "We found several problems with the information you provided.",
+                ),
+                "",
+                errors,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system_with_internal;
+
+    #[test]
+    fn test_validator() {
+        let mut v = Validator::new();
+        v.check(true, "This should not be reported.", "");
+        assert!(v.is_empty());
+        assert!(Validator::new().finish().is_ok());
+
+        v.check(
+            false,
+            "The port you provided was invalid.",
+            "Provide a port between 1 and 65535.",
+        );
+        assert!(!v.is_empty());
+        assert_eq!(
+            v.finish().unwrap_err().message(),
+            "Oh no! The port you provided was invalid.\n\nTo try and fix this, you can:\n - Provide a port between 1 and 65535."
+        );
+
+        let mut v = Validator::new();
+        v.check(
+            false,
+            "The port you provided was invalid.",
+            "Provide a port between 1 and 65535.",
+        )
+        .check(
+            false,
+            "You did not provide a name.",
+            "Provide a name using --name.",
+        )
+        .push(system_with_internal(
+            "We could not read the config file.",
+            "",
+            crate::detailed_message("EACCES"),
+        ));
+
+        let err = v.finish().unwrap_err();
+        assert!(err.is_user());
+        assert!(err.contains_system());
+
+        let mut depths = vec![];
+        err.walk(|depth, human, _| {
+            if human.is_some() {
+                depths.push(depth);
+            }
+        });
+        assert_eq!(depths, vec![0, 1, 1, 1]);
+        assert_eq!(
+            err.message(),
+            "Oh no! We found several problems with the information you provided.\n\nThis was caused by:\n - The port you provided was invalid.\n - You did not provide a name.\n - We could not read the config file.\n - EACCES\n\nTo try and fix this, you can:\n - Provide a port between 1 and 65535.\n - Provide a name using --name."
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::RwLock;
+
+static VERBOSITY: RwLock<Option<Verbosity>> = RwLock::new(None);
+
+/// Controls how much detail is included when an error's message is rendered.
+///
+/// [Error::message](crate::Error::message), [Error::message_with](crate::Error::message_with)
+/// and [std::fmt::Display] use [Verbosity::Normal] unless you call [set_verbosity].
+///
+/// The renderers which present errors directly to your users ([crate::render_to],
+/// [crate::DedupReporter] and `ResultExt::err_to_exit`) read the verbosity each time
+/// they render an error. It is taken from [set_verbosity] if one has been set, otherwise
+/// from the `HUMAN_ERRORS_VERBOSITY` environment variable (which may be `minimal`,
+/// `normal` or `full`), and otherwise defaults to [Verbosity::Normal]. This allows your
+/// users to get more detail for a bug report without your application needing to
+/// provide a flag for it.
+///
+/// | Verbosity   | Description | Advice | Causes | Internal error details |
+/// |-------------|-------------|--------|--------|------------------------|
+/// | `Minimal`   | Yes         | Yes    | No     | No                     |
+/// | `Normal`    | Yes         | Yes    | Yes    | No                     |
+/// | `Full`      | Yes         | Yes    | Yes    | Yes                    |
+///
+/// The internal error details are the [std::fmt::Debug] representation of each internal
+/// error in the causal chain, which is where most error types expose details such as
+/// error codes, locations and backtraces. [crate::Error::public_message] never includes
+/// them, regardless of the verbosity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verbosity {
+    /// Only the description and advice are shown.
+    Minimal,
+
+    /// The description, causes and advice are shown.
+    #[default]
+    Normal,
+
+    /// Everything shown by [Verbosity::Normal] is shown, along with internal error details.
+    Full,
+}
+
+/// Overrides the verbosity used when rendering error messages.
+///
+/// Passing `None` removes the override, so that messages are rendered at
+/// [Verbosity::Normal] and the renderers use the `HUMAN_ERRORS_VERBOSITY`
+/// environment variable once again. See [Verbosity] for details.
+///
+/// # Examples
+/// ```
+/// use human_errors::{self, Verbosity};
+///
+/// human_errors::set_verbosity(Some(Verbosity::Minimal));
+///
+/// let err = human_errors::user_with_cause(
+///   "We could not open the config file you provided.",
+///   "Make sure that you've specified a valid config file with the --config option.",
+///   human_errors::user("We could not find a file at /home/user/.config/demo.yml", "")
+/// );
+///
+/// assert_eq!(
+///   err.message(),
+///   "Oh no! We could not open the config file you provided.\n\nTo try and fix this, you can:\n - Make sure that you've specified a valid config file with the --config option."
+/// );
+/// ```
+pub fn set_verbosity(verbosity: Option<Verbosity>) {
+    let mut current = VERBOSITY.write().unwrap_or_else(|err| err.into_inner());
+    *current = verbosity;
+}
+
+pub(crate) fn verbosity() -> Verbosity {
+    let current = VERBOSITY.read().unwrap_or_else(|err| err.into_inner());
+    current.unwrap_or_default()
+}
+
+pub(crate) fn render_verbosity() -> Verbosity {
+    let current = VERBOSITY.read().unwrap_or_else(|err| err.into_inner());
+
+    current.unwrap_or_else(|| {
+        std::env::var("HUMAN_ERRORS_VERBOSITY")
+            .map(|value| parse_verbosity(&value))
+            .unwrap_or_default()
+    })
+}
+
+fn parse_verbosity(value: &str) -> Verbosity {
+    match value.trim().to_lowercase().as_str() {
+        "minimal" => Verbosity::Minimal,
+        "full" => Verbosity::Full,
+        _ => Verbosity::Normal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(parse_verbosity("minimal"), Verbosity::Minimal);
+        assert_eq!(parse_verbosity(" Full "), Verbosity::Full);
+        assert_eq!(parse_verbosity("normal"), Verbosity::Normal);
+        assert_eq!(parse_verbosity("verbose"), Verbosity::Normal);
+    }
+}